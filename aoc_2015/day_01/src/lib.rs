@@ -1,77 +1,87 @@
 use anyhow::{anyhow, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 1;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<i32> {
-    let mut floor = 0;
-    for instruction in input.chars() {
-        match instruction {
-            '(' => floor += 1,
-            ')' => floor -= 1,
-            _ => return Err(anyhow!("invalid instruction: {}", instruction.escape_default())),
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+
+    type Input = Vec<i32>;
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut instructions = Vec::new();
+        for instruction in input.chars() {
+            match instruction {
+                '(' => instructions.push(1),
+                ')' => instructions.push(-1),
+                _ => return Err(anyhow!("invalid instruction: {}", instruction.escape_default())),
+            }
         }
+        Ok(instructions)
     }
-    Ok(floor)
-}
 
-pub fn part_two(input: &str) -> Result<usize> {
-    let mut floor = 0;
-    for (position, instruction) in input.char_indices() {
-        match instruction {
-            '(' => floor += 1,
-            ')' => floor -= 1,
-            _ => return Err(anyhow!("invalid instruction: {}", instruction.escape_default())),
-        }
-        if floor == -1 {
-            return Ok(position + 1);
+    fn part_one(instructions: &Self::Input) -> Result<Self::PartOne> {
+        Ok(instructions.iter().sum())
+    }
+
+    fn part_two(instructions: &Self::Input) -> Result<Self::PartTwo> {
+        let mut floor = 0;
+        for (position, instruction) in instructions.iter().enumerate() {
+            floor += instruction;
+            if floor == -1 {
+                return Ok(position + 1);
+            }
         }
+        Err(anyhow!("basement not found"))
     }
-    Err(anyhow!("basement not found"))
 }
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one("(())")?, 0);
-    assert_eq!(part_one("()()")?, 0);
+    assert_eq!(Solver::part_one(&Solver::parse("(())")?)?, 0);
+    assert_eq!(Solver::part_one(&Solver::parse("()()")?)?, 0);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("(((")?, 3);
-    assert_eq!(part_one("(()(()(")?, 3);
+    assert_eq!(Solver::part_one(&Solver::parse("(((")?)?, 3);
+    assert_eq!(Solver::part_one(&Solver::parse("(()(()(")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_one_example3() -> Result<()> {
-    assert_eq!(part_one("))(((((")?, 3);
+    assert_eq!(Solver::part_one(&Solver::parse("))(((((")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_one_example4() -> Result<()> {
-    assert_eq!(part_one("())")?, -1);
-    assert_eq!(part_one("))(")?, -1);
+    assert_eq!(Solver::part_one(&Solver::parse("())")?)?, -1);
+    assert_eq!(Solver::part_one(&Solver::parse("))(")?)?, -1);
     Ok(())
 }
 
 #[test]
 fn part_one_example5() -> Result<()> {
-    assert_eq!(part_one(")))")?, -3);
-    assert_eq!(part_one(")())())")?, -3);
+    assert_eq!(Solver::part_one(&Solver::parse(")))")?)?, -3);
+    assert_eq!(Solver::part_one(&Solver::parse(")())())")?)?, -3);
     Ok(())
 }
 
 #[test]
 fn part_two_example1() -> Result<()> {
-    assert_eq!(part_two(")")?, 1);
+    assert_eq!(Solver::part_two(&Solver::parse(")")?)?, 1);
     Ok(())
 }
 
 #[test]
 fn part_two_example2() -> Result<()> {
-    assert_eq!(part_two("()())")?, 5);
+    assert_eq!(Solver::part_two(&Solver::parse("()())")?)?, 5);
     Ok(())
 }
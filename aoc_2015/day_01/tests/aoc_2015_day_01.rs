@@ -1,17 +1,14 @@
 use anyhow::Result;
+use aoc::{answers, Part};
 
-use aoc_2015_day_01::*;
+use aoc_2015_day_01::Solver;
 
 #[test]
 fn part_one_answer() -> Result<()> {
-    let input = include_str!("../input/input.txt");
-    assert_eq!(part_one(input)?, 138);
-    Ok(())
+    answers::check::<Solver>(Part::One)
 }
 
 #[test]
 fn part_two_answer() -> Result<()> {
-    let input = include_str!("../input/input.txt");
-    assert_eq!(part_two(input)?, 1771);
-    Ok(())
+    answers::check::<Solver>(Part::Two)
 }
@@ -1,29 +1,33 @@
 use std::str::FromStr;
 
 use anyhow::{anyhow, Error, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 2;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u32> {
-    let mut paper = 0;
-    for line in input.trim().lines() {
-        let present: Present = line.parse()?;
-        paper += present.paper();
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
+
+    type Input = Vec<Present>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.trim().lines().map(str::parse).collect()
+    }
+
+    fn part_one(presents: &Self::Input) -> Result<Self::PartOne> {
+        Ok(presents.iter().map(Present::paper).sum())
     }
-    Ok(paper)
-}
 
-pub fn part_two(input: &str) -> Result<u32> {
-    let mut ribbon = 0;
-    for line in input.trim().lines() {
-        let present: Present = line.parse()?;
-        ribbon += present.ribbon();
+    fn part_two(presents: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(presents.iter().map(Present::ribbon).sum())
     }
-    Ok(ribbon)
 }
 
-struct Present {
+pub struct Present {
     length: u32,
     width: u32,
     height: u32,
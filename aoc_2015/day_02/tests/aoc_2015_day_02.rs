@@ -1,17 +1,14 @@
 use anyhow::Result;
+use aoc::{answers, Part};
 
-use aoc_2015_day_02::*;
+use aoc_2015_day_02::Solver;
 
 #[test]
 fn part_one_answer() -> Result<()> {
-    let input = include_str!("../input/input.txt");
-    assert_eq!(part_one(input)?, 1588178);
-    Ok(())
+    answers::check::<Solver>(Part::One)
 }
 
 #[test]
 fn part_two_answer() -> Result<()> {
-    let input = include_str!("../input/input.txt");
-    assert_eq!(part_two(input)?, 3783758);
-    Ok(())
+    answers::check::<Solver>(Part::Two)
 }
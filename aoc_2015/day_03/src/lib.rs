@@ -1,85 +1,92 @@
 use std::collections::HashSet;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
+use aoc::{
+    geom::{Direction, Point2},
+    Solution,
+};
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 3;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<usize> {
-    let mut houses = HashSet::new();
-    let mut santa = Location::default();
-    houses.insert(santa);
-    for step in input.chars() {
-        santa.step(step)?;
-        houses.insert(santa);
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+
+    type Input = Vec<Direction>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.chars().map(Direction::from_arrow).collect()
     }
-    Ok(houses.len())
-}
 
-pub fn part_two(input: &str) -> Result<usize> {
-    let mut houses = HashSet::new();
-    houses.insert(Location::default());
-    let mut santa = Location::default();
-    let mut robot = Location::default();
-    for step in input.chars().step_by(2) {
-        santa.step(step)?;
+    fn part_one(moves: &Self::Input) -> Result<Self::PartOne> {
+        let mut houses = HashSet::new();
+        let mut santa = Point2::<i32>::default();
         houses.insert(santa);
+        for step in moves {
+            santa = santa.step(*step);
+            houses.insert(santa);
+        }
+        Ok(houses.len())
     }
-    for step in input.chars().skip(1).step_by(2) {
-        robot.step(step)?;
-        houses.insert(robot);
-    }
-    Ok(houses.len())
-}
-
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-struct Location(i32, i32);
 
-impl Location {
-    fn step(&mut self, step: char) -> Result<()> {
-        match step {
-            '>' => self.0 += 1,
-            '<' => self.0 -= 1,
-            '^' => self.1 += 1,
-            'v' => self.1 -= 1,
-            _ => return Err(anyhow!("invalid move: '{}'", step.escape_default())),
+    fn part_two(moves: &Self::Input) -> Result<Self::PartTwo> {
+        let mut houses = HashSet::new();
+        houses.insert(Point2::<i32>::default());
+        let mut santa = Point2::default();
+        let mut robot = Point2::default();
+        for step in moves.iter().step_by(2) {
+            santa = santa.step(*step);
+            houses.insert(santa);
+        }
+        for step in moves.iter().skip(1).step_by(2) {
+            robot = robot.step(*step);
+            houses.insert(robot);
         }
-        Ok(())
+        Ok(houses.len())
     }
 }
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one(">")?, 2);
+    assert_eq!(Solver::part_one(&Solver::parse(">")?)?, 2);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("^>v<")?, 4);
+    assert_eq!(Solver::part_one(&Solver::parse("^>v<")?)?, 4);
     Ok(())
 }
 
 #[test]
 fn part_one_example3() -> Result<()> {
-    assert_eq!(part_one("^v^v^v^v^v")?, 2);
+    assert_eq!(Solver::part_one(&Solver::parse("^v^v^v^v^v")?)?, 2);
     Ok(())
 }
 
 #[test]
 fn part_two_example1() -> Result<()> {
-    assert_eq!(part_two("^v")?, 3);
+    assert_eq!(Solver::part_two(&Solver::parse("^v")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_two_example2() -> Result<()> {
-    assert_eq!(part_two("^>v<")?, 3);
+    assert_eq!(Solver::part_two(&Solver::parse("^>v<")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_two_example3() -> Result<()> {
-    assert_eq!(part_two("^v^v^v^v^v")?, 11);
+    assert_eq!(Solver::part_two(&Solver::parse("^v^v^v^v^v")?)?, 11);
     Ok(())
 }
+
+#[test]
+fn invalid_moves() {
+    assert!(Solver::parse("^N").is_err());
+    assert!(Solver::parse("R").is_err());
+}
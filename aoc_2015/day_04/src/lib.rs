@@ -1,47 +1,59 @@
 use anyhow::Result;
+use aoc::Solution;
 use md5::{digest::generic_array::GenericArray, Digest, Md5};
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 3;
-
-pub fn part_one(input: &str) -> Result<u32> {
-    let input = input.trim();
-    let mut hasher = Md5::new();
-    let mut array = GenericArray::default();
-    for int in 1.. {
-        hasher.update(input);
-        hasher.update(int.to_string());
-        hasher.finalize_into_reset(&mut array);
-        if array[..2] == [0, 0] && array[2] <= 0x0F {
-            return Ok(int);
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+
+    type Input = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_owned())
+    }
+
+    fn part_one(key: &Self::Input) -> Result<Self::PartOne> {
+        let mut hasher = Md5::new();
+        let mut array = GenericArray::default();
+        for int in 1.. {
+            hasher.update(key);
+            hasher.update(int.to_string());
+            hasher.finalize_into_reset(&mut array);
+            if array[..2] == [0, 0] && array[2] <= 0x0F {
+                return Ok(int);
+            }
         }
+        unreachable!()
     }
-    unreachable!()
-}
 
-pub fn part_two(input: &str) -> Result<u32> {
-    let input = input.trim();
-    let mut hasher = Md5::new();
-    let mut array = GenericArray::default();
-    for int in 1.. {
-        hasher.update(input);
-        hasher.update(int.to_string());
-        hasher.finalize_into_reset(&mut array);
-        if array[..3] == [0, 0, 0] {
-            return Ok(int);
+    fn part_two(key: &Self::Input) -> Result<Self::PartTwo> {
+        let mut hasher = Md5::new();
+        let mut array = GenericArray::default();
+        for int in 1.. {
+            hasher.update(key);
+            hasher.update(int.to_string());
+            hasher.finalize_into_reset(&mut array);
+            if array[..3] == [0, 0, 0] {
+                return Ok(int);
+            }
         }
+        unreachable!()
     }
-    unreachable!()
 }
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one("abcdef")?, 609043);
+    assert_eq!(Solver::part_one(&Solver::parse("abcdef")?)?, 609043);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("pqrstuv")?, 1048970);
+    assert_eq!(Solver::part_one(&Solver::parse("pqrstuv")?)?, 1048970);
     Ok(())
 }
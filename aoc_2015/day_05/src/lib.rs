@@ -1,28 +1,30 @@
 use std::collections::HashSet;
 
 use anyhow::Result;
+use aoc::Solution;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 5;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u32> {
-    let mut nice = 0;
-    for line in input.trim().lines() {
-        if is_nice_one(line) {
-            nice += 1;
-        }
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().lines().map(str::to_owned).collect())
     }
-    Ok(nice)
-}
 
-pub fn part_two(input: &str) -> Result<u32> {
-    let mut nice = 0;
-    for line in input.trim().lines() {
-        if is_nice_two(line) {
-            nice += 1;
-        }
+    fn part_one(strings: &Self::Input) -> Result<Self::PartOne> {
+        Ok(strings.iter().filter(|string| is_nice_one(string)).count())
+    }
+
+    fn part_two(strings: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(strings.iter().filter(|string| is_nice_two(string)).count())
     }
-    Ok(nice)
 }
 
 fn is_nice_one(string: &str) -> bool {
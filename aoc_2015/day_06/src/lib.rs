@@ -1,35 +1,54 @@
 use anyhow::{anyhow, Context, Result};
+use aoc::{
+    grid::{Grid, ViewMut},
+    Solution,
+};
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 6;
-
-pub fn part_one(input: &str) -> Result<usize> {
-    let mut grid = Grid::default();
-    for line in input.trim().lines() {
-        let (action, range) = parse_instruction(line)?;
-        match action {
-            Action::TurnOff => grid.turn_off(range),
-            Action::TurnOn => grid.turn_on(range),
-            Action::Toggle => grid.toggle(range),
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+
+    type Input = Vec<Instruction>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.trim().lines().map(parse_instruction).collect()
+    }
+
+    fn part_one(instructions: &Self::Input) -> Result<Self::PartOne> {
+        let mut grid = Grid::new(1000, 1000, 0);
+        for instruction in instructions {
+            for light in instruction.lights(&mut grid)?.values_mut() {
+                *light = match instruction.action {
+                    Action::TurnOff => 0,
+                    Action::TurnOn => 1,
+                    Action::Toggle => 1 - *light,
+                }
+            }
         }
+        Ok(grid.values().sum())
     }
-    Ok(grid.count())
-}
 
-pub fn part_two(input: &str) -> Result<usize> {
-    let mut grid = Grid::default();
-    for line in input.trim().lines() {
-        let (action, range) = parse_instruction(line)?;
-        match action {
-            Action::TurnOff => grid.decrease_by(1, range),
-            Action::TurnOn => grid.increase_by(1, range),
-            Action::Toggle => grid.increase_by(2, range),
+    fn part_two(instructions: &Self::Input) -> Result<Self::PartTwo> {
+        let mut grid = Grid::new(1000, 1000, 0);
+        for instruction in instructions {
+            for light in instruction.lights(&mut grid)?.values_mut() {
+                *light = match instruction.action {
+                    Action::TurnOff => light.saturating_sub(1),
+                    Action::TurnOn => *light + 1,
+                    Action::Toggle => *light + 2,
+                }
+            }
         }
+        Ok(grid.values().sum())
     }
-    Ok(grid.count())
 }
 
-fn parse_instruction(line: &str) -> Result<(Action, Range)> {
+fn parse_instruction(line: &str) -> Result<Instruction> {
     let (action, from, to) = if line.starts_with("turn off") {
         let mut iter = line.split_whitespace().skip(2);
         let from = iter.next().with_context(|| format!("invalid instruction: '{}'", line))?;
@@ -50,8 +69,11 @@ fn parse_instruction(line: &str) -> Result<(Action, Range)> {
     };
     let (xmin, ymin) = parse_indices(from)?;
     let (xmax, ymax) = parse_indices(to)?;
+    if xmin > xmax || ymin > ymax {
+        return Err(anyhow!("invalid range: '{}'", line));
+    }
     let range = Range { xmin, xmax, ymin, ymax };
-    Ok((action, range))
+    Ok(Instruction { action, range })
 }
 
 fn parse_indices(str: &str) -> Result<(usize, usize)> {
@@ -61,69 +83,22 @@ fn parse_indices(str: &str) -> Result<(usize, usize)> {
     Ok((x, y))
 }
 
-#[derive(Debug)]
-struct Grid {
-    lights: Vec<usize>,
-}
-
-impl Grid {
-    fn turn_on(&mut self, range: Range) {
-        for x in range.xmin..=range.xmax {
-            for y in range.ymin..=range.ymax {
-                let idx = x * 1000 + y;
-                self.lights[idx] = 1
-            }
-        }
-    }
-
-    fn turn_off(&mut self, range: Range) {
-        for x in range.xmin..=range.xmax {
-            for y in range.ymin..=range.ymax {
-                let idx = x * 1000 + y;
-                self.lights[idx] = 0
-            }
-        }
-    }
-
-    fn toggle(&mut self, range: Range) {
-        for x in range.xmin..=range.xmax {
-            for y in range.ymin..=range.ymax {
-                let idx = x * 1000 + y;
-                self.lights[idx] = if self.lights[idx] == 1 { 0 } else { 1 }
-            }
-        }
-    }
-
-    fn increase_by(&mut self, step: usize, range: Range) {
-        for x in range.xmin..=range.xmax {
-            for y in range.ymin..=range.ymax {
-                let idx = x * 1000 + y;
-                self.lights[idx] += step
-            }
-        }
-    }
-
-    fn decrease_by(&mut self, step: usize, range: Range) {
-        for x in range.xmin..=range.xmax {
-            for y in range.ymin..=range.ymax {
-                let idx = x * 1000 + y;
-                self.lights[idx] = self.lights[idx].saturating_sub(step);
-            }
-        }
-    }
-
-    fn count(&self) -> usize {
-        self.lights.iter().sum()
-    }
+#[derive(Clone, Copy, Debug)]
+pub struct Instruction {
+    action: Action,
+    range: Range,
 }
 
-impl Default for Grid {
-    fn default() -> Self {
-        Self { lights: vec![0; 1_000_000] }
+impl Instruction {
+    /// Lights of the grid in the range of the instruction.
+    fn lights<'a>(&self, grid: &'a mut Grid<usize>) -> Result<ViewMut<'a, usize>> {
+        let Range { xmin, xmax, ymin, ymax } = self.range;
+        grid.view_mut(xmin, ymin, xmax - xmin + 1, ymax - ymin + 1)
+            .with_context(|| format!("range out of the grid: {:?}", self.range))
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 struct Range {
     xmin: usize,
     xmax: usize,
@@ -131,6 +106,7 @@ struct Range {
     ymax: usize,
 }
 
+#[derive(Clone, Copy, Debug)]
 enum Action {
     TurnOff,
     TurnOn,
@@ -139,31 +115,31 @@ enum Action {
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one("turn on 0,0 through 999,999")?, 1_000_000);
+    assert_eq!(Solver::part_one(&Solver::parse("turn on 0,0 through 999,999")?)?, 1_000_000);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("toggle 0,0 through 999,0")?, 1000);
-    assert_eq!(part_one("toggle 0,0 through 999,0\ntoggle 0,0 through 999,0")?, 0);
+    assert_eq!(Solver::part_one(&Solver::parse("toggle 0,0 through 999,0")?)?, 1000);
+    assert_eq!(Solver::part_one(&Solver::parse("toggle 0,0 through 999,0\ntoggle 0,0 through 999,0")?)?, 0);
     Ok(())
 }
 
 #[test]
 fn part_one_example3() -> Result<()> {
-    assert_eq!(part_one("turn on 0,0 through 999,999\nturn off 499,499 through 500,500")?, 1_000_000 - 4);
+    assert_eq!(Solver::part_one(&Solver::parse("turn on 0,0 through 999,999\nturn off 499,499 through 500,500")?)?, 1_000_000 - 4);
     Ok(())
 }
 
 #[test]
 fn part_two_example1() -> Result<()> {
-    assert_eq!(part_two("turn on 0,0 through 0,0")?, 1);
+    assert_eq!(Solver::part_two(&Solver::parse("turn on 0,0 through 0,0")?)?, 1);
     Ok(())
 }
 
 #[test]
 fn part_two_example2() -> Result<()> {
-    assert_eq!(part_two("toggle 0,0 through 999,999")?, 2_000_000);
+    assert_eq!(Solver::part_two(&Solver::parse("toggle 0,0 through 999,999")?)?, 2_000_000);
     Ok(())
 }
@@ -1,26 +1,42 @@
 use std::collections::HashMap;
 
 use anyhow::{anyhow, Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 7;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u16> {
-    let mut signals = HashMap::new();
-    solve(input.trim(), &mut signals, ["a"])?;
-    let signal = *signals.get("a").context("invalid circuit")?;
-    Ok(signal)
-}
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
 
-pub fn part_two(input: &str) -> Result<u16> {
-    let mut signals = HashMap::new();
-    solve(input.trim(), &mut signals, ["a"])?;
-    let signal = *signals.get("a").context("invalid circuit")?;
-    signals.clear();
-    signals.insert("b", signal);
-    solve(input.trim(), &mut signals, ["a"])?;
-    let signal = *signals.get("a").context("invalid circuit")?;
-    Ok(signal)
+    type Input = String;
+    type PartOne = u16;
+    type PartTwo = u16;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        circuit(input)?;
+        Ok(input.to_owned())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        let mut signals = HashMap::new();
+        solve(input, &mut signals, ["a"])?;
+        let signal = *signals.get("a").context("invalid circuit")?;
+        Ok(signal)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        let mut signals = HashMap::new();
+        solve(input, &mut signals, ["a"])?;
+        let signal = *signals.get("a").context("invalid circuit")?;
+        signals.clear();
+        signals.insert("b", signal);
+        solve(input, &mut signals, ["a"])?;
+        let signal = *signals.get("a").context("invalid circuit")?;
+        Ok(signal)
+    }
 }
 
 fn solve<'a>(input: &'a str, signals: &mut HashMap<&'a str, u16>, wires: impl IntoIterator<Item = &'a str>) -> Result<()> {
@@ -134,7 +150,7 @@ fn solve<'a>(input: &'a str, signals: &mut HashMap<&'a str, u16>, wires: impl In
     Ok(())
 }
 
-fn circuit(circuit: &str) -> Result<HashMap<&str, Gate>> {
+fn circuit(circuit: &str) -> Result<HashMap<&str, Gate<'_>>> {
     let mut map = HashMap::new();
     for line in circuit.lines() {
         let (prefix, suffix) = line.split_once("->").with_context(|| format!("invalid circuit: '{}'", line))?;
@@ -144,7 +160,7 @@ fn circuit(circuit: &str) -> Result<HashMap<&str, Gate>> {
     Ok(map)
 }
 
-fn gate(gate: &str) -> Result<Gate> {
+fn gate(gate: &str) -> Result<Gate<'_>> {
     if gate.contains("NOT") {
         let wire = gate.split_ascii_whitespace().nth(1).with_context(|| format!("invalid gate: '{}'", gate))?;
         let connection = connection(wire);
@@ -180,8 +196,8 @@ fn gate(gate: &str) -> Result<Gate> {
     }
 }
 
-fn connection(connection: &str) -> Connection {
-    if connection.chars().all(|c| c.is_digit(10)) {
+fn connection(connection: &str) -> Connection<'_> {
+    if connection.chars().all(|c| c.is_ascii_digit()) {
         Connection::Provider(connection.parse().unwrap())
     } else {
         Connection::Wire(connection)
@@ -1,26 +1,40 @@
 use anyhow::{anyhow, Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 8;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<usize> {
-    let mut length = 0;
-    let mut size = 0;
-    for line in input.lines() {
-        length += string_length(line.trim())?;
-        size += string_size(line.trim())?;
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Matchsticks";
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.trim().to_owned()).collect())
     }
-    Ok(length - size)
-}
 
-pub fn part_two(input: &str) -> Result<usize> {
-    let mut length = 0;
-    let mut encoded = 0;
-    for line in input.lines() {
-        length += string_length(line.trim())?;
-        encoded += string_encode_length(line.trim())?;
+    fn part_one(strings: &Self::Input) -> Result<Self::PartOne> {
+        let mut length = 0;
+        let mut size = 0;
+        for string in strings {
+            length += string_length(string)?;
+            size += string_size(string)?;
+        }
+        Ok(length - size)
+    }
+
+    fn part_two(strings: &Self::Input) -> Result<Self::PartTwo> {
+        let mut length = 0;
+        let mut encoded = 0;
+        for string in strings {
+            length += string_length(string)?;
+            encoded += string_encode_length(string)?;
+        }
+        Ok(encoded - length)
     }
-    Ok(encoded - length)
 }
 
 fn string_length(str: &str) -> Result<usize> {
@@ -1,12 +1,74 @@
-use anyhow::Result;
+use std::collections::HashMap;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 9;
+use anyhow::{anyhow, Result};
+use aoc::{
+    tsp::{self, Objective, Tour},
+    Solution,
+};
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+
+    type Input = Vec<Vec<u32>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut locations = HashMap::new();
+        let mut routes = Vec::new();
+        for line in input.lines() {
+            let route = line.split_once(" to ").and_then(|(from, rest)| {
+                let (to, distance) = rest.split_once(" = ")?;
+                Some((from, to, distance.parse::<u32>().ok()?))
+            });
+            let (from, to, distance) = route.ok_or_else(|| anyhow!("invalid route: '{}'", line))?;
+            let count = locations.len();
+            let from = *locations.entry(from).or_insert(count);
+            let count = locations.len();
+            let to = *locations.entry(to).or_insert(count);
+            routes.push((from, to, distance));
+        }
+        let mut distances = vec![vec![0; locations.len()]; locations.len()];
+        for (from, to, distance) in routes {
+            distances[from][to] = distance;
+            distances[to][from] = distance;
+        }
+        Ok(distances)
+    }
+
+    fn part_one(distances: &Self::Input) -> Result<Self::PartOne> {
+        let route = tsp::solve(distances, Objective::Minimise, Tour::Open, None).ok_or_else(|| anyhow!("no locations"))?;
+        Ok(route.cost)
+    }
+
+    fn part_two(distances: &Self::Input) -> Result<Self::PartTwo> {
+        let route = tsp::solve(distances, Objective::Maximise, Tour::Open, None).ok_or_else(|| anyhow!("no locations"))?;
+        Ok(route.cost)
+    }
+}
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let input = "\
+London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141
+";
+    assert_eq!(Solver::part_one(&Solver::parse(input)?)?, 605);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let input = "\
+London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141
+";
+    assert_eq!(Solver::part_two(&Solver::parse(input)?)?, 982);
+    Ok(())
 }
@@ -1,12 +1,26 @@
 use anyhow::Result;
+use aoc::Solution;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 12;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
-}
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    type Input = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::PartOne> {
+        Ok(1)
+    }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+    fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(2)
+    }
 }
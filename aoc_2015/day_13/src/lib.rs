@@ -1,12 +1,88 @@
-use anyhow::Result;
+use std::collections::HashMap;
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 13;
+use anyhow::{anyhow, Result};
+use aoc::{
+    tsp::{self, Objective, Tour},
+    Solution,
+};
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+
+    type Input = Vec<Vec<i32>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut guests = HashMap::new();
+        let mut preferences = Vec::new();
+        for line in input.lines() {
+            let preference = line.strip_suffix('.').and_then(|line| {
+                let (guest, rest) = line.split_once(" would ")?;
+                let (change, neighbour) = rest.split_once(" happiness units by sitting next to ")?;
+                let happiness = match change.split_once(' ')? {
+                    ("gain", units) => units.parse::<i32>().ok()?,
+                    ("lose", units) => -units.parse::<i32>().ok()?,
+                    _ => return None,
+                };
+                Some((guest, neighbour, happiness))
+            });
+            let (guest, neighbour, happiness) = preference.ok_or_else(|| anyhow!("invalid preference: '{}'", line))?;
+            let count = guests.len();
+            let guest = *guests.entry(guest).or_insert(count);
+            let count = guests.len();
+            let neighbour = *guests.entry(neighbour).or_insert(count);
+            preferences.push((guest, neighbour, happiness));
+        }
+        let mut happiness = vec![vec![0; guests.len()]; guests.len()];
+        for (guest, neighbour, change) in preferences {
+            happiness[guest][neighbour] += change;
+            happiness[neighbour][guest] += change;
+        }
+        Ok(happiness)
+    }
+
+    fn part_one(happiness: &Self::Input) -> Result<Self::PartOne> {
+        seat(happiness)
+    }
+
+    fn part_two(happiness: &Self::Input) -> Result<Self::PartTwo> {
+        // Yourself, indifferent to everyone.
+        let mut happiness = happiness.clone();
+        for row in &mut happiness {
+            row.push(0);
+        }
+        happiness.push(vec![0; happiness.len() + 1]);
+        seat(&happiness)
+    }
+}
+
+/// Total change in happiness of the best seating around the table.
+fn seat(happiness: &[Vec<i32>]) -> Result<i32> {
+    let seating = tsp::solve(happiness, Objective::Maximise, Tour::Closed, None).ok_or_else(|| anyhow!("no guests"))?;
+    Ok(seating.cost)
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_one_example1() -> Result<()> {
+    let input = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.
+";
+    assert_eq!(Solver::part_one(&Solver::parse(input)?)?, 330);
+    Ok(())
 }
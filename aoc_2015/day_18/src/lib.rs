@@ -1,12 +1,73 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    automaton::{Dense, Rule},
+    grid::Grid,
+    Solution,
+};
 
-pub const YEAR: u32 = 2015;
-pub const DAY: u32 = 18;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2015;
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Like a GIF For Your Yard";
+
+    type Input = Grid<bool>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(anyhow!("invalid light: '{}'", c)),
+        })
+    }
+
+    fn part_one(lights: &Self::Input) -> Result<Self::PartOne> {
+        Ok(animate(lights, 100, false))
+    }
+
+    fn part_two(lights: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(animate(lights, 100, true))
+    }
+}
+
+/// Number of lights on after some steps, the corners being stuck on or not.
+fn animate(lights: &Grid<bool>, steps: usize, stuck: bool) -> usize {
+    let (width, height) = (lights.width(), lights.height());
+    let corner = |(x, y)| stuck && (x == 0 || x + 1 == width) && (y == 0 || y + 1 == height);
+    let dense = Dense::moore(lights);
+    let mut lights = lights.clone();
+    for (position, light) in lights.iter_mut() {
+        *light |= corner(position);
+    }
+    for _ in 0..steps {
+        lights = dense.step(&lights, |position, &on, neighbours| corner(position) || Rule::LIFE.apply(on, neighbours.filter(|&&on| on).count()));
+    }
+    lights.values().filter(|&&on| on).count()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+.#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..
+";
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let lights = Solver::parse(EXAMPLE)?;
+    assert_eq!(animate(&lights, 4, false), 4);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let lights = Solver::parse(EXAMPLE)?;
+    assert_eq!(animate(&lights, 5, true), 17);
+    Ok(())
 }
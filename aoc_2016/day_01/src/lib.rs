@@ -1,135 +1,91 @@
 use std::collections::HashSet;
 
 use anyhow::{anyhow, Result};
-
-pub const YEAR: u32 = 2016;
-pub const DAY: u32 = 1;
-
-pub fn part_one(input: &str) -> Result<i32> {
-    let mut position = Position::default();
-    for instruction in input.trim().split(',').map(|str| str.trim()) {
-        match instruction.chars().next() {
-            Some('L') => position.turn_left(),
-            Some('R') => position.turn_right(),
-            _ => return Err(anyhow!("invalid instruction: '{}'", instruction)),
-        }
-        let blocks = match instruction.get(1..).map(|str| str.parse()) {
-            Some(Ok(x)) => x,
-            _ => return Err(anyhow!("invalid instruction: '{}'", instruction)),
-        };
-        position.walk(blocks);
-    }
-    Ok(position.distance())
-}
-
-pub fn part_two(input: &str) -> Result<i32> {
-    let mut position = Position::default();
-    let mut visited = HashSet::new();
-    for instruction in input.trim().split(',').map(|str| str.trim()) {
-        match instruction.chars().next() {
-            Some('L') => position.turn_left(),
-            Some('R') => position.turn_right(),
-            _ => return Err(anyhow!("invalid instruction: '{}'", instruction)),
-        }
-        let blocks = match instruction.get(1..).map(|str| str.parse()) {
-            Some(Ok(x)) => x,
-            _ => return Err(anyhow!("invalid instruction: '{}'", instruction)),
-        };
-        for _ in 0..blocks {
-            position.step();
-            if !visited.insert(position.coordinates) {
-                return Ok(position.distance());
-            }
-        }
-    }
-    Err(anyhow!("Easter Bunny HQ not found"))
-}
-
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
-#[derive(Debug)]
-struct Position {
-    direction: Direction,
-    coordinates: (i32, i32),
-}
-
-impl Position {
-    fn turn_right(&mut self) {
-        match self.direction {
-            Direction::North => self.direction = Direction::East,
-            Direction::East => self.direction = Direction::South,
-            Direction::South => self.direction = Direction::West,
-            Direction::West => self.direction = Direction::North,
-        }
-    }
-
-    fn turn_left(&mut self) {
-        match self.direction {
-            Direction::North => self.direction = Direction::West,
-            Direction::West => self.direction = Direction::South,
-            Direction::South => self.direction = Direction::East,
-            Direction::East => self.direction = Direction::North,
+use aoc::{
+    geom::{Direction, Point2, Turn},
+    Solution,
+};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2016;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "No Time for a Taxicab";
+
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut instructions = Vec::new();
+        for instruction in input.trim().split(',').map(|str| str.trim()) {
+            let turn = match instruction.chars().next().map(Turn::try_from) {
+                Some(Ok(turn)) => turn,
+                _ => return Err(anyhow!("invalid instruction: '{}'", instruction)),
+            };
+            let blocks = match instruction.get(1..).map(|str| str.parse()) {
+                Some(Ok(x)) => x,
+                _ => return Err(anyhow!("invalid instruction: '{}'", instruction)),
+            };
+            instructions.push(Instruction { turn, blocks });
         }
+        Ok(instructions)
     }
 
-    fn walk(&mut self, blocks: i32) {
-        match self.direction {
-            Direction::North => self.coordinates.1 += blocks,
-            Direction::West => self.coordinates.0 += blocks,
-            Direction::South => self.coordinates.1 -= blocks,
-            Direction::East => self.coordinates.0 -= blocks,
+    fn part_one(instructions: &Self::Input) -> Result<Self::PartOne> {
+        let mut direction = Direction::North;
+        let mut position = Point2::default();
+        for instruction in instructions {
+            direction = direction.turn(instruction.turn);
+            position = position.walk(direction, instruction.blocks);
         }
+        Ok(position.manhattan(Point2::default()))
     }
 
-    fn step(&mut self) {
-        match self.direction {
-            Direction::North => self.coordinates.1 += 1,
-            Direction::West => self.coordinates.0 += 1,
-            Direction::South => self.coordinates.1 -= 1,
-            Direction::East => self.coordinates.0 -= 1,
+    fn part_two(instructions: &Self::Input) -> Result<Self::PartTwo> {
+        let mut direction = Direction::North;
+        let mut position = Point2::default();
+        let mut visited = HashSet::new();
+        for instruction in instructions {
+            direction = direction.turn(instruction.turn);
+            for _ in 0..instruction.blocks {
+                position = position.step(direction);
+                if !visited.insert(position) {
+                    return Ok(position.manhattan(Point2::default()));
+                }
+            }
         }
-    }
-
-    fn distance(&self) -> i32 {
-        self.coordinates.0.abs() + self.coordinates.1.abs()
+        Err(anyhow!("Easter Bunny HQ not found"))
     }
 }
 
-impl Default for Position {
-    fn default() -> Self {
-        Self {
-            direction: Direction::North,
-            coordinates: Default::default(),
-        }
-    }
+#[derive(Clone, Copy, Debug)]
+pub struct Instruction {
+    turn: Turn,
+    blocks: i32,
 }
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one("R2, L3")?, 5);
+    assert_eq!(Solver::part_one(&Solver::parse("R2, L3")?)?, 5);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("R2, R2, R2")?, 2);
+    assert_eq!(Solver::part_one(&Solver::parse("R2, R2, R2")?)?, 2);
     Ok(())
 }
 
 #[test]
 fn part_one_example3() -> Result<()> {
-    assert_eq!(part_one("R5, L5, R5, R3")?, 12);
+    assert_eq!(Solver::part_one(&Solver::parse("R5, L5, R5, R3")?)?, 12);
     Ok(())
 }
 
 #[test]
 fn part_two_example1() -> Result<()> {
-    assert_eq!(part_two("R8, R4, R4, R8")?, 4);
+    assert_eq!(Solver::part_two(&Solver::parse("R8, R4, R4, R8")?)?, 4);
     Ok(())
 }
@@ -1,12 +1,71 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    grid::Grid,
+    search,
+    tsp::{self, Objective, Tour},
+    Solution,
+};
 
-pub const YEAR: u32 = 2016;
-pub const DAY: u32 = 24;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2016;
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Air Duct Spelunking";
+
+    type Input = Vec<Vec<usize>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map: Grid<char> = input.parse()?;
+        let mut locations = Vec::new();
+        for ((x, y), &c) in map.iter() {
+            match c {
+                '#' | '.' => {}
+                _ => match c.to_digit(10) {
+                    Some(number) => locations.push((number as usize, (x, y))),
+                    None => return Err(anyhow!("invalid cell: '{}'", c)),
+                },
+            }
+        }
+        locations.sort_unstable();
+        if locations.iter().enumerate().any(|(i, &(number, _))| i != number) {
+            return Err(anyhow!("locations are not numbered from 0"));
+        }
+
+        let open = |&(x, y): &(usize, usize)| map.neighbours4(x, y).filter(|&(x, y)| map[(x, y)] != '#').collect::<Vec<_>>();
+        let mut steps = Vec::new();
+        for &(number, start) in &locations {
+            let distances = search::distances(start, open, None);
+            let row = locations
+                .iter()
+                .map(|(to, position)| distances.get(position).copied().ok_or_else(|| anyhow!("cannot reach {} from {}", to, number)));
+            steps.push(row.collect::<Result<_>>()?);
+        }
+        Ok(steps)
+    }
+
+    fn part_one(steps: &Self::Input) -> Result<Self::PartOne> {
+        let route = tsp::solve(steps, Objective::Minimise, Tour::Open, Some(0)).ok_or_else(|| anyhow!("no location 0"))?;
+        Ok(route.cost)
+    }
+
+    fn part_two(steps: &Self::Input) -> Result<Self::PartTwo> {
+        let route = tsp::solve(steps, Objective::Minimise, Tour::Closed, Some(0)).ok_or_else(|| anyhow!("no location 0"))?;
+        Ok(route.cost)
+    }
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_one_example1() -> Result<()> {
+    let input = "\
+###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+###########
+";
+    assert_eq!(Solver::part_one(&Solver::parse(input)?)?, 14);
+    Ok(())
 }
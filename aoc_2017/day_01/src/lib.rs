@@ -1,85 +1,100 @@
 use anyhow::{Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2017;
-pub const DAY: u32 = 1;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u32> {
-    let input = input.trim();
-    let len = input.len() + 1;
-    let mut sum = 0;
-    let mut iter = input.chars().cycle().take(len).peekable();
-    while let Some(digit) = iter.next() {
-        if iter.peek() == Some(&digit) {
-            sum += digit.to_digit(10).with_context(|| format!("invalid digit '{}'", digit.escape_default()))?;
+impl Solution for Solver {
+    const YEAR: u32 = 2017;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Inverse Captcha";
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut sequence = Vec::new();
+        for digit in input.trim().chars() {
+            sequence.push(digit.to_digit(10).with_context(|| format!("invalid digit '{}'", digit.escape_default()))?);
         }
+        Ok(sequence)
+    }
+
+    fn part_one(sequence: &Self::Input) -> Result<Self::PartOne> {
+        let len = sequence.len() + 1;
+        let mut sum = 0;
+        let mut iter = sequence.iter().cycle().take(len).peekable();
+        while let Some(digit) = iter.next() {
+            if iter.peek() == Some(&digit) {
+                sum += digit;
+            }
+        }
+        Ok(sum)
     }
-    Ok(sum)
-}
 
-pub fn part_two(input: &str) -> Result<u32> {
-    let input = input.trim();
-    let len = input.len();
-    let sequence: Vec<_> = input.chars().collect();
-    let mut sum = 0;
-    for (idx, digit) in sequence.iter().enumerate() {
-        let halfway = (idx + len / 2) % len;
-        if sequence[halfway] == *digit {
-            sum += digit.to_digit(10).with_context(|| format!("invalid digit '{}'", digit.escape_default()))?;
+    fn part_two(sequence: &Self::Input) -> Result<Self::PartTwo> {
+        let len = sequence.len();
+        let mut sum = 0;
+        for (idx, digit) in sequence.iter().enumerate() {
+            let halfway = (idx + len / 2) % len;
+            if sequence[halfway] == *digit {
+                sum += digit;
+            }
         }
+        Ok(sum)
     }
-    Ok(sum)
 }
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one("1122")?, 3);
+    assert_eq!(Solver::part_one(&Solver::parse("1122")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("1111")?, 4);
+    assert_eq!(Solver::part_one(&Solver::parse("1111")?)?, 4);
     Ok(())
 }
 
 #[test]
 fn part_one_example3() -> Result<()> {
-    assert_eq!(part_one("1234")?, 0);
+    assert_eq!(Solver::part_one(&Solver::parse("1234")?)?, 0);
     Ok(())
 }
 
 #[test]
 fn part_one_example4() -> Result<()> {
-    assert_eq!(part_one("91212129")?, 9);
+    assert_eq!(Solver::part_one(&Solver::parse("91212129")?)?, 9);
     Ok(())
 }
 
 #[test]
 fn part_two_example1() -> Result<()> {
-    assert_eq!(part_two("1212")?, 6);
+    assert_eq!(Solver::part_two(&Solver::parse("1212")?)?, 6);
     Ok(())
 }
 
 #[test]
 fn part_two_example2() -> Result<()> {
-    assert_eq!(part_two("1221")?, 0);
+    assert_eq!(Solver::part_two(&Solver::parse("1221")?)?, 0);
     Ok(())
 }
 
 #[test]
 fn part_two_example3() -> Result<()> {
-    assert_eq!(part_two("123425")?, 4);
+    assert_eq!(Solver::part_two(&Solver::parse("123425")?)?, 4);
     Ok(())
 }
 
 #[test]
 fn part_two_example4() -> Result<()> {
-    assert_eq!(part_two("123123")?, 12);
+    assert_eq!(Solver::part_two(&Solver::parse("123123")?)?, 12);
     Ok(())
 }
 
 #[test]
 fn part_two_example5() -> Result<()> {
-    assert_eq!(part_two("12131415")?, 4);
+    assert_eq!(Solver::part_two(&Solver::parse("12131415")?)?, 4);
     Ok(())
 }
@@ -1,12 +1,58 @@
 use anyhow::Result;
+use aoc::{
+    hex::{FlatDirection, Hex},
+    Solution,
+};
 
-pub const YEAR: u32 = 2017;
-pub const DAY: u32 = 11;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2017;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Hex Ed";
+
+    type Input = Vec<FlatDirection>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.trim().split(',').map(str::parse).collect()
+    }
+
+    fn part_one(path: &Self::Input) -> Result<Self::PartOne> {
+        let child = path.iter().fold(Hex::default(), |hex, &direction| hex.step(direction));
+        Ok(child.distance(Hex::default()))
+    }
+
+    fn part_two(path: &Self::Input) -> Result<Self::PartTwo> {
+        let positions = path.iter().scan(Hex::default(), |hex, &direction| {
+            *hex = hex.step(direction);
+            Some(*hex)
+        });
+        Ok(positions.map(|hex| hex.distance(Hex::default())).max().unwrap_or(0))
+    }
+}
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    assert_eq!(Solver::part_one(&Solver::parse("ne,ne,ne")?)?, 3);
+    Ok(())
+}
+
+#[test]
+fn part_one_example2() -> Result<()> {
+    assert_eq!(Solver::part_one(&Solver::parse("ne,ne,sw,sw")?)?, 0);
+    Ok(())
+}
+
+#[test]
+fn part_one_example3() -> Result<()> {
+    assert_eq!(Solver::part_one(&Solver::parse("ne,ne,s,s")?)?, 2);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_one_example4() -> Result<()> {
+    assert_eq!(Solver::part_one(&Solver::parse("se,sw,se,sw,sw")?)?, 3);
+    Ok(())
 }
@@ -1,81 +1,95 @@
 use std::collections::HashSet;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2018;
-pub const DAY: u32 = 1;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<i32> {
-    let mut frequency = 0i32;
-    for change in input.trim().lines() {
-        frequency += change.parse::<i32>().with_context(|| format!("invalid frequency change: '{}'", change))?;
+impl Solution for Solver {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Chronal Calibration";
+
+    type Input = Vec<i32>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut changes = Vec::new();
+        for change in input.trim().lines() {
+            changes.push(change.parse::<i32>().with_context(|| format!("invalid frequency change: '{}'", change))?);
+        }
+        Ok(changes)
+    }
+
+    fn part_one(changes: &Self::Input) -> Result<Self::PartOne> {
+        Ok(changes.iter().sum())
     }
-    Ok(frequency)
-}
 
-pub fn part_two(input: &str) -> Result<i32> {
-    let mut frequency = 0i32;
-    let mut seen = HashSet::new();
-    seen.insert(frequency);
-    for change in input.trim().lines().cycle() {
-        frequency += change.parse::<i32>().with_context(|| format!("invalid frequency change: '{}'", change))?;
-        if !seen.insert(frequency) {
-            return Ok(frequency);
+    fn part_two(changes: &Self::Input) -> Result<Self::PartTwo> {
+        let mut frequency = 0i32;
+        let mut seen = HashSet::new();
+        seen.insert(frequency);
+        for change in changes.iter().cycle() {
+            frequency += change;
+            if !seen.insert(frequency) {
+                return Ok(frequency);
+            }
         }
+        Err(anyhow!("frequency changes not found"))
     }
-    unreachable!();
 }
 
 #[test]
 fn part_one_example1() -> Result<()> {
-    assert_eq!(part_one("+1\n-2\n+3\n+1\n")?, 3);
+    assert_eq!(Solver::part_one(&Solver::parse("+1\n-2\n+3\n+1\n")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_one_example2() -> Result<()> {
-    assert_eq!(part_one("+1\n+1\n+1\n")?, 3);
+    assert_eq!(Solver::part_one(&Solver::parse("+1\n+1\n+1\n")?)?, 3);
     Ok(())
 }
 
 #[test]
 fn part_one_example3() -> Result<()> {
-    assert_eq!(part_one("+1\n+1\n-2\n")?, 0);
+    assert_eq!(Solver::part_one(&Solver::parse("+1\n+1\n-2\n")?)?, 0);
     Ok(())
 }
 
 #[test]
 fn part_one_example4() -> Result<()> {
-    assert_eq!(part_one("-1\n-2\n-3\n")?, -6);
+    assert_eq!(Solver::part_one(&Solver::parse("-1\n-2\n-3\n")?)?, -6);
     Ok(())
 }
 
 #[test]
 fn part_two_example1() -> Result<()> {
-    assert_eq!(part_two("+1\n-2\n+3\n+1\n")?, 2);
+    assert_eq!(Solver::part_two(&Solver::parse("+1\n-2\n+3\n+1\n")?)?, 2);
     Ok(())
 }
 
 #[test]
 fn part_two_example2() -> Result<()> {
-    assert_eq!(part_two("+1\n-1\n")?, 0);
+    assert_eq!(Solver::part_two(&Solver::parse("+1\n-1\n")?)?, 0);
     Ok(())
 }
 
 #[test]
 fn part_two_example3() -> Result<()> {
-    assert_eq!(part_two("+3\n+3\n+4\n-2\n-4\n")?, 10);
+    assert_eq!(Solver::part_two(&Solver::parse("+3\n+3\n+4\n-2\n-4\n")?)?, 10);
     Ok(())
 }
 
 #[test]
 fn part_two_example4() -> Result<()> {
-    assert_eq!(part_two("-6\n+3\n+8\n+5\n-6\n")?, 5);
+    assert_eq!(Solver::part_two(&Solver::parse("-6\n+3\n+8\n+5\n-6\n")?)?, 5);
     Ok(())
 }
 
 #[test]
 fn part_two_example5() -> Result<()> {
-    assert_eq!(part_two("+7\n+7\n-2\n-7\n-4\n")?, 14);
+    assert_eq!(Solver::part_two(&Solver::parse("+7\n+7\n-2\n-7\n-4\n")?)?, 14);
     Ok(())
 }
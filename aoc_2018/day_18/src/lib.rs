@@ -1,12 +1,88 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    automaton::{Cycle, Dense},
+    grid::Grid,
+    Solution,
+};
 
-pub const YEAR: u32 = 2018;
-pub const DAY: u32 = 18;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2018;
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Settlers of The North Pole";
+
+    type Input = Grid<Acre>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| match c {
+            '.' => Ok(Acre::Open),
+            '|' => Ok(Acre::Trees),
+            '#' => Ok(Acre::Lumberyard),
+            _ => Err(anyhow!("invalid acre: '{}'", c)),
+        })
+    }
+
+    fn part_one(area: &Self::Input) -> Result<Self::PartOne> {
+        let dense = Dense::moore(area);
+        let area = (0..10).fold(area.clone(), |area, _| step(&dense, &area));
+        Ok(resource_value(&area))
+    }
+
+    fn part_two(area: &Self::Input) -> Result<Self::PartTwo> {
+        let dense = Dense::moore(area);
+        let cycle = Cycle::find(area.clone(), |area| step(&dense, area));
+        Ok(resource_value(cycle.nth(1_000_000_000)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Acre {
+    Open,
+    Trees,
+    Lumberyard,
+}
+
+fn step(dense: &Dense, area: &Grid<Acre>) -> Grid<Acre> {
+    dense.step(area, |_, &acre, neighbours| {
+        let (mut trees, mut lumberyards) = (0, 0);
+        for &neighbour in neighbours {
+            match neighbour {
+                Acre::Trees => trees += 1,
+                Acre::Lumberyard => lumberyards += 1,
+                Acre::Open => {}
+            }
+        }
+        match acre {
+            Acre::Open if trees >= 3 => Acre::Trees,
+            Acre::Trees if lumberyards >= 3 => Acre::Lumberyard,
+            Acre::Lumberyard if trees == 0 || lumberyards == 0 => Acre::Open,
+            acre => acre,
+        }
+    })
+}
+
+fn resource_value(area: &Grid<Acre>) -> usize {
+    let count = |kind| area.values().filter(|&&acre| acre == kind).count();
+    count(Acre::Trees) * count(Acre::Lumberyard)
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_one_example1() -> Result<()> {
+    let input = "\
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.
+";
+    assert_eq!(Solver::part_one(&Solver::parse(input)?)?, 1147);
+    Ok(())
 }
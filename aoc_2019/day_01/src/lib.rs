@@ -1,24 +1,37 @@
 use anyhow::{Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 1;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u32> {
-    let mut fuel = 0;
-    for mass in input.trim().lines() {
-        let mass = mass.parse().with_context(|| format!("invalid mass: '{}'", mass))?;
-        fuel += fuel_requirement(mass);
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "The Tyranny of the Rocket Equation";
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut masses = Vec::new();
+        for mass in input.trim().lines() {
+            masses.push(mass.parse().with_context(|| format!("invalid mass: '{}'", mass))?);
+        }
+        Ok(masses)
     }
-    Ok(fuel)
-}
 
-pub fn part_two(input: &str) -> Result<i32> {
-    let mut fuel = 0;
-    for mass in input.trim().lines() {
-        let mass = mass.parse().with_context(|| format!("invalid mass: '{}'", mass))?;
-        fuel += recursive_fuel_requirement(mass);
+    fn part_one(masses: &Self::Input) -> Result<Self::PartOne> {
+        Ok(masses.iter().map(|mass| fuel_requirement(*mass)).sum())
+    }
+
+    fn part_two(masses: &Self::Input) -> Result<Self::PartTwo> {
+        let mut fuel = 0;
+        for mass in masses {
+            let mass = i32::try_from(*mass).with_context(|| format!("invalid mass: '{}'", mass))?;
+            fuel += recursive_fuel_requirement(mass);
+        }
+        Ok(fuel)
     }
-    Ok(fuel)
 }
 
 fn fuel_requirement(mass: u32) -> u32 {
@@ -1,12 +1,67 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+use aoc_2019_intcode::Machine;
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 2;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "1202 Program Alarm";
+
+    type Input = Vec<i64>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        run(program, 12, 2)
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        for noun in 0..100 {
+            for verb in 0..100 {
+                // A pair making the program fail is not the answer either.
+                if run(program, noun, verb).is_ok_and(|output| output == 19690720) {
+                    return Ok(100 * noun + verb);
+                }
+            }
+        }
+        Err(anyhow!("no noun and verb produce 19690720"))
+    }
+}
+
+/// Value left at address 0 once the program halts, given its noun and verb.
+fn run(program: &[i64], noun: i64, verb: i64) -> Result<i64> {
+    let mut machine = Machine::new(program);
+    machine.write(1, noun)?;
+    machine.write(2, verb)?;
+    machine.run_to_end()?;
+    Ok(machine.read(0))
+}
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let program = Solver::parse("1,9,10,3,2,3,11,0,99,30,40,50")?;
+    assert_eq!(run(&program, 9, 10)?, 3500);
+    Ok(())
+}
+
+#[test]
+fn part_one_example2() -> Result<()> {
+    let program = Solver::parse("1,1,1,4,99,5,6,0,99")?;
+    assert_eq!(run(&program, 1, 1)?, 30);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn failing_programs() -> Result<()> {
+    // Adding the largest value to itself overflows.
+    let program = Solver::parse("1,0,0,0,99,9223372036854775807")?;
+    assert!(run(&program, 5, 5).is_err());
+    assert_eq!(run(&program, 0, 0)?, 2);
+    Ok(())
 }
@@ -1,12 +1,47 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+use aoc_2019_intcode::Machine;
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 5;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Sunny with a Chance of Asteroids";
+
+    type Input = Vec<i64>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        diagnostic(program, 1)
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        diagnostic(program, 5)
+    }
+}
+
+/// Diagnostic code of the system with an ID, every test before it having to output 0.
+fn diagnostic(program: &[i64], system: i64) -> Result<i64> {
+    let mut machine = Machine::new(program);
+    machine.input(system);
+    let outputs = machine.run_to_end()?;
+    let (&code, tests) = outputs.split_last().ok_or_else(|| anyhow!("no diagnostic code"))?;
+    if let Some(position) = tests.iter().position(|&output| output != 0) {
+        return Err(anyhow!("test {} failed: {}", position + 1, tests[position]));
+    }
+    Ok(code)
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let program = Solver::parse("3,9,8,9,10,9,4,9,99,-1,8")?;
+    assert_eq!(diagnostic(&program, 8)?, 1);
+    assert_eq!(diagnostic(&program, 5)?, 0);
+    Ok(())
 }
@@ -1,12 +1,81 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{search, Solution};
+use aoc_2019_intcode::{Event, Machine, Network};
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 7;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Amplification Circuit";
+
+    type Input = Vec<i64>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        best_signal(program, [0, 1, 2, 3, 4], false)
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        best_signal(program, [5, 6, 7, 8, 9], true)
+    }
+}
+
+const AMPLIFIERS: usize = 5;
+
+/// Highest signal sent to the thrusters over every order of the phase settings.
+fn best_signal(program: &[i64], mut phases: [i64; AMPLIFIERS], feedback: bool) -> Result<i64> {
+    let mut best = None;
+    search::for_each_permutation(&mut phases, |phases| {
+        let signal = signal(program, phases, feedback)?;
+        best = best.max(Some(signal));
+        Ok(())
+    })?;
+    best.ok_or_else(|| anyhow!("no phase settings"))
+}
+
+/// Signal sent to the thrusters by the last amplifier, once it halts when in a feedback loop.
+fn signal(program: &[i64], phases: &[i64], feedback: bool) -> Result<i64> {
+    let mut network = Network::new(vec![Machine::new(program); AMPLIFIERS], 1);
+    for (amplifier, &phase) in phases.iter().enumerate() {
+        network.send(amplifier, [phase])?;
+    }
+    network.send(0, [0])?;
+    let mut signal = None;
+    let event = network.run(|from, values| {
+        if from + 1 < AMPLIFIERS {
+            return Some((from + 1, values.to_vec()));
+        }
+        signal = Some(values[0]);
+        feedback.then(|| (0, values.to_vec()))
+    })?;
+    match event {
+        Event::Message { .. } | Event::Halted => signal.ok_or_else(|| anyhow!("no signal sent to the thrusters")),
+        Event::Idle => Err(anyhow!("amplifiers waiting for input")),
+    }
+}
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    assert_eq!(Solver::part_one(&Solver::parse("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0")?)?, 43210);
+    Ok(())
+}
+
+#[test]
+fn part_one_example2() -> Result<()> {
+    let input = "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0";
+    assert_eq!(Solver::part_one(&Solver::parse(input)?)?, 54321);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let input = "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5";
+    assert_eq!(Solver::part_two(&Solver::parse(input)?)?, 139629729);
+    Ok(())
 }
@@ -1,12 +1,58 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    geom::{Direction, Point2},
+    Solution,
+};
+use aoc_2019_intcode::{Machine, Screen, State};
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 11;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Space Police";
+
+    type Input = Vec<i64>;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        Ok(paint(program, false)?.len())
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        let hull = paint(program, true)?;
+        let picture = hull.render(|white| if white == Some(&true) { '#' } else { '.' });
+        aoc::ocr::read(&picture)
+    }
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+/// Panels painted by the robot, white or black, starting on a panel of the given colour.
+fn paint(program: &[i64], white: bool) -> Result<Screen<bool>> {
+    let mut hull = Screen::new();
+    hull.draw(Point2::default(), white);
+    let mut robot = Machine::new(program);
+    let (mut position, mut direction) = (Point2::default(), Direction::North);
+    let mut outputs = Vec::with_capacity(2);
+    loop {
+        match robot.run()? {
+            State::Input => robot.input(i64::from(hull.get(position) == Some(&true))),
+            State::Output(value) => outputs.push(value),
+            State::Halted => return Ok(hull),
+        }
+        if let [colour, turn] = outputs[..] {
+            hull.draw(position, colour == 1);
+            direction = match turn {
+                0 => direction.turn_left(),
+                1 => direction.turn_right(),
+                _ => return Err(anyhow!("invalid turn: {}", turn)),
+            };
+            position = position.step(direction);
+            outputs.clear();
+        }
+    }
 }
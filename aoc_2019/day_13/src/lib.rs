@@ -1,12 +1,143 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{geom::Point2, Solution};
+use aoc_2019_intcode::{Machine, Screen, State};
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 13;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Care Package";
+
+    type Input = Vec<i64>;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        let mut cabinet = Cabinet::new(program);
+        cabinet.play()?;
+        Ok(cabinet.screen.tiles().filter(|&(_, &tile)| tile == Tile::Block).count())
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        let mut cabinet = Cabinet::new(program);
+        // Two quarters.
+        cabinet.machine.write(0, 2)?;
+        cabinet.play()?;
+        if cabinet.screen.tiles().any(|(_, &tile)| tile == Tile::Block) {
+            let screen = cabinet.screen.render(Tile::pixel);
+            return Err(anyhow!("game over with blocks left, at a score of {}:\n{}", cabinet.score, screen));
+        }
+        Ok(cabinet.score)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl Tile {
+    /// Character of a tile on a text screen.
+    fn pixel(tile: Option<&Tile>) -> char {
+        match tile {
+            Some(Tile::Wall) => '#',
+            Some(Tile::Block) => '=',
+            Some(Tile::Paddle) => '-',
+            Some(Tile::Ball) => 'o',
+            Some(Tile::Empty) | None => ' ',
+        }
+    }
+}
+
+impl TryFrom<i64> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Tile::Empty),
+            1 => Ok(Tile::Wall),
+            2 => Ok(Tile::Block),
+            3 => Ok(Tile::Paddle),
+            4 => Ok(Tile::Ball),
+            _ => Err(anyhow!("invalid tile: {}", value)),
+        }
+    }
+}
+
+/// Arcade cabinet running the game, its joystick following the ball.
+struct Cabinet {
+    machine: Machine,
+    screen: Screen<Tile>,
+    score: i64,
+    ball: i64,
+    paddle: i64,
+}
+
+impl Cabinet {
+    /// Position of the segment display showing the score, instead of a tile.
+    const SCORE: Point2<i64> = Point2::new(-1, 0);
+
+    fn new(program: &[i64]) -> Self {
+        Self {
+            machine: Machine::new(program),
+            screen: Screen::new(),
+            score: 0,
+            ball: 0,
+            paddle: 0,
+        }
+    }
+
+    /// Play until the game ends, moving the paddle under the ball whenever the joystick is read.
+    fn play(&mut self) -> Result<()> {
+        let mut outputs = Vec::new();
+        loop {
+            let state = self.machine.run()?;
+            if let State::Output(value) = state {
+                outputs.push(value);
+                continue;
+            }
+            self.draw(&outputs)?;
+            outputs.clear();
+            match state {
+                State::Input => self.machine.input((self.ball - self.paddle).signum()),
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn draw(&mut self, outputs: &[i64]) -> Result<()> {
+        let (score, ball, paddle) = (&mut self.score, &mut self.ball, &mut self.paddle);
+        self.screen.update(outputs, |position, value| {
+            if position == Self::SCORE {
+                *score = value;
+                return Ok(None);
+            }
+            let tile = Tile::try_from(value)?;
+            match tile {
+                Tile::Ball => *ball = position.x,
+                Tile::Paddle => *paddle = position.x,
+                _ => {}
+            }
+            Ok(Some(tile))
+        })
+    }
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn draw() -> Result<()> {
+    let mut cabinet = Cabinet::new(&[99]);
+    cabinet.draw(&[0, 0, 1, 1, 0, 1, 2, 0, 1, 1, 1, 4, 1, 2, 3, -1, 0, 12345])?;
+    assert_eq!(cabinet.screen.render(Tile::pixel), "###\n o \n - \n");
+    assert_eq!((cabinet.score, cabinet.ball, cabinet.paddle), (12345, 1, 1));
+    assert!(cabinet.draw(&[0, 0, 5]).is_err());
+    Ok(())
 }
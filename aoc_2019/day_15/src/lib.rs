@@ -1,12 +1,127 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    geom::{Direction, Point2},
+    search, Solution,
+};
+use aoc_2019_intcode::{Machine, Screen, State};
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 15;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Oxygen System";
+
+    type Input = Vec<i64>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        let area = map(program)?;
+        let path = search::bfs(Point2::default(), |&position| open(&area, position), |&position| area.get(position) == Some(&Tile::Oxygen), None);
+        path.map(|path| path.cost).ok_or_else(|| anyhow!("no oxygen system found:\n{}", area.render(Tile::pixel)))
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        spread(&map(program)?)
+    }
+}
+
+/// Minutes for the oxygen to fill the area from the oxygen system.
+fn spread(area: &Screen<Tile>) -> Result<usize> {
+    let (system, _) = area.tiles().find(|&(_, &tile)| tile == Tile::Oxygen).ok_or_else(|| anyhow!("no oxygen system found"))?;
+    let minutes = search::distances(system, |&position| open(area, position), None);
+    Ok(minutes.into_values().max().unwrap_or_default())
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Tile {
+    Wall,
+    Open,
+    Oxygen,
+}
+
+impl Tile {
+    /// Character of a tile on a text map, unexplored positions being blank.
+    fn pixel(tile: Option<&Tile>) -> char {
+        match tile {
+            Some(Tile::Wall) => '#',
+            Some(Tile::Open) => '.',
+            Some(Tile::Oxygen) => 'O',
+            None => ' ',
+        }
+    }
+}
+
+/// Positions next to another that the droid can move to.
+fn open(area: &Screen<Tile>, position: Point2<i64>) -> impl Iterator<Item = Point2<i64>> + '_ {
+    position.neighbours4().filter(|&neighbour| matches!(area.get(neighbour), Some(Tile::Open | Tile::Oxygen)))
+}
+
+/// Map of the area, explored depth first by the repair droid from its starting position.
+fn map(program: &[i64]) -> Result<Screen<Tile>> {
+    let mut droid = Machine::new(program);
+    let mut area = Screen::new();
+    area.draw(Point2::default(), Tile::Open);
+    explore(&mut droid, &mut area, Point2::default())?;
+    Ok(area)
+}
+
+/// Try every unexplored direction from a position, going back to it after each move.
+fn explore(droid: &mut Machine, area: &mut Screen<Tile>, position: Point2<i64>) -> Result<()> {
+    for direction in Direction::ALL {
+        let next = position.step(direction);
+        if area.get(next).is_some() {
+            continue;
+        }
+        let tile = walk(droid, direction)?;
+        area.draw(next, tile);
+        if tile != Tile::Wall {
+            explore(droid, area, next)?;
+            if walk(droid, direction.reverse())? == Tile::Wall {
+                return Err(anyhow!("droid cannot step back from {} to {}", next, position));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Move the droid, returning what it found in that direction.
+fn walk(droid: &mut Machine, direction: Direction) -> Result<Tile> {
+    droid.input(match direction {
+        Direction::North => 1,
+        Direction::South => 2,
+        Direction::West => 3,
+        Direction::East => 4,
+    });
+    match droid.run()? {
+        State::Output(0) => Ok(Tile::Wall),
+        State::Output(1) => Ok(Tile::Open),
+        State::Output(2) => Ok(Tile::Oxygen),
+        state => Err(anyhow!("invalid status: {:?}", state)),
+    }
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let input = " ##   \n#..## \n#.#..#\n#.O.# \n ###  ";
+    let mut area = Screen::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let tile = match c {
+                '#' => Tile::Wall,
+                '.' => Tile::Open,
+                'O' => Tile::Oxygen,
+                _ => continue,
+            };
+            area.draw(Point2::new(x as i64, y as i64), tile);
+        }
+    }
+    assert_eq!(area.render(Tile::pixel), format!("{}\n", input));
+    assert_eq!(spread(&area)?, 4);
+    Ok(())
 }
@@ -1,12 +1,67 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+use aoc_2019_intcode::{Event, Machine, Network};
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 23;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "Category Six";
+
+    type Input = Vec<i64>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        let mut network = boot(program)?;
+        match network.run(route)? {
+            Event::Message { values, .. } if values[0] == NAT => Ok(values[2]),
+            Event::Message { values, .. } => Err(anyhow!("invalid address: {}", values[0])),
+            event => Err(anyhow!("no packet sent to address {}: {:?}", NAT, event)),
+        }
+    }
+
+    fn part_two(program: &Self::Input) -> Result<Self::PartTwo> {
+        let mut network = boot(program)?;
+        let mut packet = None;
+        let mut last_y = None;
+        loop {
+            match network.run(route)? {
+                Event::Message { values, .. } if values[0] == NAT => packet = Some((values[1], values[2])),
+                Event::Message { values, .. } => return Err(anyhow!("invalid address: {}", values[0])),
+                Event::Idle => {
+                    let (x, y) = packet.ok_or_else(|| anyhow!("network idle without any packet for the NAT"))?;
+                    if last_y == Some(y) {
+                        return Ok(y);
+                    }
+                    last_y = Some(y);
+                    network.send(0, [x, y])?;
+                }
+                Event::Halted => return Err(anyhow!("network halted")),
+            }
+        }
+    }
+}
+
+const COMPUTERS: usize = 50;
+const NAT: i64 = 255;
+
+/// Network of computers given their address, packets being sent as an address followed by `x` and `y`.
+fn boot(program: &[i64]) -> Result<Network> {
+    let mut network = Network::new(vec![Machine::new(program); COMPUTERS], 3).with_idle_input(-1);
+    for address in 0..COMPUTERS {
+        network.send(address, [address as i64])?;
+    }
+    Ok(network)
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+/// Packets to anything but a computer, such as the NAT, leave the network.
+fn route(_: usize, packet: &[i64]) -> Option<(usize, Vec<i64>)> {
+    let address = usize::try_from(packet[0]).ok().filter(|&address| address < COMPUTERS)?;
+    Some((address, packet[1..].to_vec()))
 }
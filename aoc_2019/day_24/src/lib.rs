@@ -1,12 +1,113 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    automaton::{Cycle, Dense, Rule, Sparse},
+    grid::Grid,
+    Solution,
+};
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 24;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Planet of Discord";
+
+    type Input = Grid<bool>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let eris = Grid::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(anyhow!("invalid tile: '{}'", c)),
+        })?;
+        if (eris.width(), eris.height()) != (SIZE, SIZE) {
+            return Err(anyhow!("invalid size: {}x{}", eris.width(), eris.height()));
+        }
+        Ok(eris)
+    }
+
+    fn part_one(eris: &Self::Input) -> Result<Self::PartOne> {
+        let dense = Dense::von_neumann(eris);
+        let cycle = Cycle::find(eris.clone(), |eris| dense.step(eris, |_, &bug, neighbours| RULE.apply(bug, neighbours.filter(|&&bug| bug).count())));
+        Ok(cycle.repeated().values().enumerate().filter(|&(_, &bug)| bug).map(|(i, _)| 1 << i).sum())
+    }
+
+    fn part_two(eris: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(recursive_bugs(eris, 200))
+    }
+}
+
+const SIZE: usize = 5;
+
+/// Bugs are born next to one or two bugs and survive next to exactly one.
+const RULE: Rule = Rule::new(&[1, 2], &[1]);
+
+/// Tile of a level of the recursive grids, the centre tile of each level holding the next one.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Tile {
+    level: i32,
+    x: usize,
+    y: usize,
+}
+
+impl Tile {
+    fn neighbours(self) -> Vec<Tile> {
+        let Tile { level, x, y } = self;
+        let centre = SIZE / 2;
+        let mut neighbours = Vec::new();
+        for (dx, dy) in [(0, -1), (-1, 0), (1, 0), (0, 1)] {
+            match (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+                (Some(x), Some(y)) if (x, y) == (centre, centre) => {
+                    // Every tile of the inner edge facing the move.
+                    let inner = |i| match (dx, dy) {
+                        (0, -1) => (i, SIZE - 1),
+                        (0, _) => (i, 0),
+                        (-1, _) => (SIZE - 1, i),
+                        _ => (0, i),
+                    };
+                    neighbours.extend((0..SIZE).map(inner).map(|(x, y)| Tile { level: level + 1, x, y }));
+                }
+                (Some(x), Some(y)) if x < SIZE && y < SIZE => neighbours.push(Tile { level, x, y }),
+                _ => neighbours.push(Tile {
+                    level: level - 1,
+                    x: centre.saturating_add_signed(dx),
+                    y: centre.saturating_add_signed(dy),
+                }),
+            }
+        }
+        neighbours
+    }
+}
+
+fn recursive_bugs(eris: &Grid<bool>, minutes: usize) -> usize {
+    let mut bugs = Sparse::new(eris.iter().filter(|&(_, &bug)| bug).map(|((x, y), _)| Tile { level: 0, x, y }));
+    for _ in 0..minutes {
+        bugs = bugs.step(Tile::neighbours, |bug, count| RULE.apply(bug, count));
+    }
+    bugs.len()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+....#
+#..#.
+#..##
+..#..
+#....
+";
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let eris = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_one(&eris)?, 2129920);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let eris = Solver::parse(EXAMPLE)?;
+    assert_eq!(recursive_bugs(&eris, 10), 99);
+    Ok(())
 }
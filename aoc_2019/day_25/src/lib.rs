@@ -1,12 +1,192 @@
-use anyhow::Result;
+use std::collections::HashSet;
 
-pub const YEAR: u32 = 2019;
-pub const DAY: u32 = 25;
+use anyhow::{anyhow, Result};
+use aoc::Solution;
+use aoc_2019_intcode::Ascii;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2019;
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Cryostasis";
+
+    type Input = Vec<i64>;
+    type PartOne = u64;
+    type PartTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        aoc_2019_intcode::parse(input)
+    }
+
+    fn part_one(program: &Self::Input) -> Result<Self::PartOne> {
+        let mut droid = Ascii::new(program);
+        let start = Room::parse(&droid.read()?.text)?;
+        let mut explorer = Explorer::default();
+        explorer.explore(&mut droid, start, &mut Vec::new())?;
+        let (path, floor) = explorer.checkpoint.ok_or_else(|| anyhow!("no security checkpoint found"))?;
+        for door in &path {
+            droid.command(door)?;
+        }
+        unlock(&mut droid, &explorer.items, &floor)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+        Ok("no puzzle on the last day")
+    }
+}
+
+/// Steps given to a command before deciding that the droid is stuck in an infinite loop.
+const LIMIT: u64 = 1_000_000;
+
+/// Room as described when entering it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+impl Room {
+    /// Parse the last room described in the output, as being ejected from a room describes the one before too.
+    fn parse(text: &str) -> Result<Self> {
+        let start = text.rfind("== ").ok_or_else(|| anyhow!("no room in '{}'", text.trim()))?;
+        let mut lines = text[start..].lines();
+        let name = lines.next().unwrap_or_default().trim_matches(|c| c == '=' || c == ' ').to_owned();
+        let mut room = Room {
+            name,
+            doors: Vec::new(),
+            items: Vec::new(),
+        };
+        let mut list = None;
+        for line in lines {
+            match line {
+                "Doors here lead:" => list = Some(&mut room.doors),
+                "Items here:" => list = Some(&mut room.items),
+                _ => match (line.strip_prefix("- "), list.as_mut()) {
+                    (Some(entry), Some(list)) => list.push(entry.to_owned()),
+                    _ => list = None,
+                },
+            }
+        }
+        Ok(room)
+    }
+}
+
+/// Door leading back the way a droid came.
+fn back(door: &str) -> Result<&'static str> {
+    match door {
+        "north" => Ok("south"),
+        "south" => Ok("north"),
+        "east" => Ok("west"),
+        "west" => Ok("east"),
+        _ => Err(anyhow!("invalid door: '{}'", door)),
+    }
+}
+
+/// Knowledge gathered by walking through the ship.
+#[derive(Debug, Default)]
+struct Explorer {
+    visited: HashSet<String>,
+    /// Items picked up.
+    items: Vec<String>,
+    /// Doors from the hull breach to the security checkpoint, and the door from there to the pressure-sensitive floor.
+    checkpoint: Option<(Vec<String>, String)>,
+}
+
+impl Explorer {
+    /// Visit every room reachable from the current one, taking the safe items, and come back.
+    fn explore(&mut self, droid: &mut Ascii, room: Room, path: &mut Vec<String>) -> Result<()> {
+        self.visited.insert(room.name.clone());
+        for item in &room.items {
+            if safe(droid, item, &room.doors)? {
+                droid.command(&format!("take {}", item))?;
+                self.items.push(item.clone());
+            }
+        }
+        let entrance = path.last().map(|door| back(door)).transpose()?;
+        for door in &room.doors {
+            if Some(door.as_str()) == entrance {
+                continue;
+            }
+            if room.name == "Security Checkpoint" {
+                // Going further without the right weight gets the droid ejected.
+                self.checkpoint = Some((path.clone(), door.clone()));
+                continue;
+            }
+            let output = droid.command(door)?;
+            let next = Room::parse(&output.text)?;
+            path.push(door.clone());
+            if !self.visited.contains(&next.name) {
+                self.explore(droid, next, path)?;
+            }
+            path.pop();
+            droid.command(back(door)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the droid can carry an item and still move, tried on a copy of it.
+fn safe(droid: &Ascii, item: &str, doors: &[String]) -> Result<bool> {
+    let mut droid = droid.clone().with_limit(LIMIT);
+    let door = doors.first().ok_or_else(|| anyhow!("no door to try '{}' with", item))?;
+    let moved = droid.command(&format!("take {}", item)).and_then(|output| match output.halted {
+        true => Ok(output),
+        false => droid.command(door),
+    });
+    Ok(matches!(moved, Ok(output) if !output.halted && output.text.contains("== ")))
+}
+
+/// Find the items weighing as much as the droid should by trying every combination on the pressure-sensitive floor,
+/// then read the password on the keypad.
+fn unlock(droid: &mut Ascii, items: &[String], floor: &str) -> Result<u64> {
+    let mut held = (1u32 << items.len()) - 1;
+    // Gray code, so that a single item is taken or dropped between tries.
+    for i in 0..1u32 << items.len() {
+        let wanted = i ^ i >> 1;
+        for (bit, item) in items.iter().enumerate() {
+            let (has, wants) = (held & 1 << bit != 0, wanted & 1 << bit != 0);
+            if has != wants {
+                droid.command(&format!("{} {}", if wants { "take" } else { "drop" }, item))?;
+            }
+        }
+        held = wanted;
+        let output = droid.command(floor)?;
+        if let Some(password) = password(&output.text) {
+            return password;
+        }
+    }
+    Err(anyhow!("no combination of the items {:?} lets the droid through", items))
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+fn password(text: &str) -> Option<Result<u64>> {
+    let (_, rest) = text.split_once("typing ")?;
+    let digits = rest.split_whitespace().next()?;
+    Some(digits.parse().map_err(|_| anyhow!("invalid password: '{}'", digits)))
+}
+
+#[test]
+fn rooms() -> Result<()> {
+    let text = "
+
+== Hull Breach ==
+You got in through a hole in the floor here.
+
+Doors here lead:
+- north
+- east
+
+Items here:
+- mug
+
+Command?
+";
+    let room = Room::parse(text)?;
+    assert_eq!(room.name, "Hull Breach");
+    assert_eq!(room.doors, ["north", "east"]);
+    assert_eq!(room.items, ["mug"]);
+    assert!(Room::parse("You can't go that way.").is_err());
+    assert_eq!(password("get in by typing 1234 on the keypad").transpose()?, Some(1234));
+    Ok(())
 }
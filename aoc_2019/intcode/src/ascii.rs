@@ -0,0 +1,112 @@
+//! Programs speaking ASCII: inputs are lines of text, outputs text and the occasional large value.
+
+use anyhow::{anyhow, Result};
+
+use crate::{Machine, State};
+
+/// Output of a program until it waits for input or halts.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Output {
+    pub text: String,
+    /// Values outside of the ASCII range, such as puzzle answers.
+    pub values: Vec<i64>,
+    pub halted: bool,
+}
+
+/// Machine running an ASCII program, optionally with a limit of instructions per read and a transcript.
+#[derive(Clone, Debug)]
+pub struct Ascii {
+    machine: Machine,
+    limit: Option<u64>,
+    transcript: Option<String>,
+}
+
+impl Ascii {
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            machine: Machine::new(program),
+            limit: None,
+            transcript: None,
+        }
+    }
+
+    /// Fail reads running more instructions, to get out of programs that loop forever.
+    pub fn with_limit(mut self, steps: u64) -> Self {
+        self.limit = Some(steps);
+        self
+    }
+
+    /// Record the text in and out.
+    pub fn with_transcript(mut self) -> Self {
+        self.transcript = Some(String::new());
+        self
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    /// Text in and out so far, when recorded.
+    pub fn transcript(&self) -> Option<&str> {
+        self.transcript.as_deref()
+    }
+
+    /// Queue a line of input, a newline being appended.
+    pub fn send(&mut self, line: &str) {
+        self.machine.inputs(line.bytes().chain([b'\n']).map(i64::from));
+        if let Some(transcript) = &mut self.transcript {
+            transcript.push_str(line);
+            transcript.push('\n');
+        }
+    }
+
+    /// Run until the program waits for input or halts.
+    pub fn read(&mut self) -> Result<Output> {
+        let mut output = Output::default();
+        let mut steps = 0;
+        loop {
+            if self.limit.is_some_and(|limit| steps >= limit) {
+                return Err(anyhow!("no input read after {} steps", steps));
+            }
+            steps += 1;
+            match self.machine.step()? {
+                Some(State::Output(value)) => match u8::try_from(value) {
+                    Ok(byte) if byte.is_ascii() => output.text.push(char::from(byte)),
+                    _ => output.values.push(value),
+                },
+                Some(State::Input) => break,
+                Some(State::Halted) => {
+                    output.halted = true;
+                    break;
+                }
+                None => {}
+            }
+        }
+        if let Some(transcript) = &mut self.transcript {
+            transcript.push_str(&output.text);
+        }
+        Ok(output)
+    }
+
+    /// Send a line and read the answer.
+    pub fn command(&mut self, line: &str) -> Result<Output> {
+        self.send(line);
+        self.read()
+    }
+}
+
+#[test]
+fn echo() -> Result<()> {
+    // Echo a line, then output 1000 and halt.
+    let mut ascii = Ascii::new(&crate::parse("3,100,4,100,1008,100,10,101,1006,101,0,104,1000,99")?).with_transcript();
+    assert_eq!(ascii.read()?, Output::default());
+    let output = ascii.command("hi")?;
+    assert_eq!(output.text, "hi\n");
+    assert_eq!(output.values, [1000]);
+    assert!(output.halted);
+    assert_eq!(ascii.transcript(), Some("hi\nhi\n"));
+
+    let mut ascii = Ascii::new(&crate::parse("1105,1,0")?).with_limit(100);
+    assert!(ascii.read().is_err());
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Disassembly of programs into pseudo-assembly.
+//!
+//! Programs are decoded from the start, values that are not valid instructions being shown as data.
+//! Code and data are mixed in Intcode, so data may also be decoded as instructions by mistake.
+
+use std::collections::BTreeSet;
+
+use crate::{Instruction, Mode, Opcode};
+
+/// Pseudo-assembly of a program, a line per instruction with its address and values,
+/// the targets of jumps to constant addresses being labelled `L<address>`.
+pub fn disassemble(program: &[i64]) -> String {
+    let mut lines = Vec::new();
+    let mut address = 0;
+    while address < program.len() {
+        let instruction = Instruction::decode(program[address]).ok().filter(|instruction| address + instruction.size() <= program.len());
+        let size = instruction.map_or(1, |instruction| instruction.size());
+        lines.push((address, instruction));
+        address += size;
+    }
+
+    let mut labels = BTreeSet::new();
+    for &(address, instruction) in &lines {
+        if let Some(target) = instruction.and_then(|instruction| jump_target(program, address, &instruction)) {
+            labels.insert(target);
+        }
+    }
+
+    let mut assembly = String::new();
+    for (address, instruction) in lines {
+        if labels.contains(&address) {
+            assembly.push_str(&format!("L{}:\n", address));
+        }
+        let size = instruction.map_or(1, |instruction| instruction.size());
+        let values: Vec<_> = program[address..address + size].iter().map(i64::to_string).collect();
+        let text = match instruction {
+            Some(instruction) => {
+                let operands: Vec<_> = (0..instruction.opcode.parameters())
+                    .map(|parameter| {
+                        let value = program[address + 1 + parameter];
+                        let is_target = parameter == 1 && jump_target(program, address, &instruction).is_some_and(|target| labels.contains(&target));
+                        if is_target {
+                            format!("L{}", value)
+                        } else {
+                            instruction.modes[parameter].operand(value)
+                        }
+                    })
+                    .collect();
+                format!("{:<4} {}", instruction.opcode, operands.join(", "))
+            }
+            None => format!("data {}", program[address]),
+        };
+        assembly.push_str(&format!("{:>6}  {:<24} {}\n", address, values.join(","), text.trim_end()));
+    }
+    assembly
+}
+
+/// Constant address a jump goes to.
+fn jump_target(program: &[i64], address: usize, instruction: &Instruction) -> Option<usize> {
+    match instruction.opcode {
+        Opcode::JumpIfTrue | Opcode::JumpIfFalse if instruction.modes[1] == Mode::Immediate => usize::try_from(program[address + 2]).ok().filter(|&target| target < program.len()),
+        _ => None,
+    }
+}
+
+#[test]
+fn listing() -> anyhow::Result<()> {
+    let program = crate::parse("3,12,1105,-1,9,1101,0,0,12,4,12,99,1,7")?;
+    let expected = [
+        "     0  3,12                     in   [12]",
+        "     2  1105,-1,9                jnz  -1, L9",
+        "     5  1101,0,0,12              add  0, 0, [12]",
+        "L9:",
+        "     9  4,12                     out  [12]",
+        "    11  99                       halt",
+        "    12  1                        data 1",
+        "    13  7                        data 7",
+    ];
+    assert_eq!(disassemble(&program).lines().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        disassemble(&crate::parse("204,-3,2")?),
+        "     0  204,-3                   out  [rb-3]\n     2  2                        data 2\n"
+    );
+    Ok(())
+}
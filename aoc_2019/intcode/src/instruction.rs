@@ -0,0 +1,160 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+/// Operation of an instruction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustBase,
+    Halt,
+}
+
+impl Opcode {
+    /// Number of parameters following the opcode.
+    pub fn parameters(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    /// Whether the last parameter is an address written to.
+    pub fn writes(self) -> bool {
+        matches!(self, Opcode::Add | Opcode::Multiply | Opcode::Input | Opcode::LessThan | Opcode::Equals)
+    }
+}
+
+impl TryFrom<i64> for Opcode {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Opcode::Add),
+            2 => Ok(Opcode::Multiply),
+            3 => Ok(Opcode::Input),
+            4 => Ok(Opcode::Output),
+            5 => Ok(Opcode::JumpIfTrue),
+            6 => Ok(Opcode::JumpIfFalse),
+            7 => Ok(Opcode::LessThan),
+            8 => Ok(Opcode::Equals),
+            9 => Ok(Opcode::AdjustBase),
+            99 => Ok(Opcode::Halt),
+            _ => Err(anyhow!("invalid opcode: {}", value)),
+        }
+    }
+}
+
+/// Lowercase mnemonic, such as `add` or `jnz`.
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = match self {
+            Opcode::Add => "add",
+            Opcode::Multiply => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jnz",
+            Opcode::JumpIfFalse => "jz",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustBase => "arb",
+            Opcode::Halt => "halt",
+        };
+        f.pad(mnemonic)
+    }
+}
+
+/// How a parameter is read.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Mode {
+    /// Address of the value.
+    Position,
+    /// The value itself.
+    Immediate,
+    /// Address of the value, relative to the relative base.
+    Relative,
+}
+
+impl TryFrom<i64> for Mode {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mode::Position),
+            1 => Ok(Mode::Immediate),
+            2 => Ok(Mode::Relative),
+            _ => Err(anyhow!("invalid parameter mode: {}", value)),
+        }
+    }
+}
+
+impl Mode {
+    /// Parameter as written in pseudo-assembly: `[address]`, `value` or `[rb+offset]`.
+    pub fn operand(self, value: i64) -> String {
+        match self {
+            Mode::Position => format!("[{}]", value),
+            Mode::Immediate => value.to_string(),
+            Mode::Relative if value < 0 => format!("[rb{}]", value),
+            Mode::Relative => format!("[rb+{}]", value),
+        }
+    }
+}
+
+/// Opcode and parameter modes, encoded as `ABCDE`: `DE` is the opcode and `C`, `B`, `A` are the modes of the parameters.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    pub fn decode(value: i64) -> Result<Self> {
+        if value < 0 {
+            return Err(anyhow!("invalid instruction: {}", value));
+        }
+        let opcode = Opcode::try_from(value % 100)?;
+        let mut modes = [Mode::Position; 3];
+        for (i, mode) in modes.iter_mut().enumerate() {
+            *mode = Mode::try_from(value / 10_i64.pow(i as u32 + 2) % 10)?;
+        }
+        if value >= 100_000 {
+            return Err(anyhow!("invalid instruction: {}", value));
+        }
+        if opcode.writes() && modes[opcode.parameters() - 1] == Mode::Immediate {
+            return Err(anyhow!("invalid instruction: {}, writing in immediate mode", value));
+        }
+        Ok(Self { opcode, modes })
+    }
+
+    /// Number of values of the instruction, parameters included.
+    pub fn size(&self) -> usize {
+        1 + self.opcode.parameters()
+    }
+}
+
+#[test]
+fn decode() -> Result<()> {
+    let instruction = Instruction::decode(1002)?;
+    assert_eq!(instruction.opcode, Opcode::Multiply);
+    assert_eq!(instruction.modes, [Mode::Position, Mode::Immediate, Mode::Position]);
+    assert_eq!(instruction.size(), 4);
+    assert_eq!(Instruction::decode(204)?.modes[0], Mode::Relative);
+    assert_eq!(Instruction::decode(99)?.size(), 1);
+    assert!(Instruction::decode(13).is_err());
+    assert!(Instruction::decode(301).is_err());
+    assert!(Instruction::decode(11101).is_err());
+    assert!(Instruction::decode(101101).is_err());
+    assert!(Instruction::decode(-1).is_err());
+    assert_eq!(Mode::Relative.operand(-2), "[rb-2]");
+    assert_eq!(Mode::Position.operand(7), "[7]");
+    Ok(())
+}
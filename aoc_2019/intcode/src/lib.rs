@@ -0,0 +1,31 @@
+//! Intcode computer, run by most puzzles of 2019.
+//!
+//! Programs are comma-separated integers. A [`Machine`] runs one until it needs an input it has not been given,
+//! outputs a value or halts, so that it can be driven by a puzzle or connected to other machines.
+
+mod ascii;
+mod disassemble;
+mod instruction;
+mod machine;
+mod network;
+mod screen;
+mod trace;
+
+pub use ascii::{Ascii, Output};
+pub use disassemble::disassemble;
+pub use instruction::{Instruction, Mode, Opcode};
+pub use machine::{Machine, State, DEFAULT_MEMORY_LIMIT};
+pub use network::{Event, Network};
+pub use screen::Screen;
+pub use trace::Tracer;
+
+use anyhow::{Context, Result};
+
+/// Parse a program.
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    input
+        .trim()
+        .split(',')
+        .map(|value| value.trim().parse().with_context(|| format!("invalid value: '{}'", value)))
+        .collect()
+}
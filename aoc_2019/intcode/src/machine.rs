@@ -0,0 +1,272 @@
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::{Instruction, Mode, Opcode};
+
+/// Why a machine stopped running.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum State {
+    /// Waiting for an input, the instruction being run again once one is given.
+    Input,
+    Output(i64),
+    Halted,
+}
+
+/// Default limit of the memory of a machine, in values.
+///
+/// Puzzle programs use a few thousand values at most, while one write to an address read from hostile input
+/// would otherwise allocate gigabytes: such writes fail instead, unless the limit is changed with [`Machine::with_memory_limit`].
+pub const DEFAULT_MEMORY_LIMIT: usize = 1 << 20;
+
+/// Intcode computer, with memory growing as needed up to its limit and a queue of inputs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Machine {
+    memory: Vec<i64>,
+    ip: usize,
+    base: i64,
+    inputs: VecDeque<i64>,
+    halted: bool,
+    memory_limit: Option<usize>,
+}
+
+impl Machine {
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            memory: program.to_vec(),
+            memory_limit: Some(DEFAULT_MEMORY_LIMIT),
+            ..Self::default()
+        }
+    }
+
+    /// Limit the memory to a number of values, or let it grow without limit.
+    pub fn with_memory_limit(mut self, limit: Option<usize>) -> Self {
+        self.memory_limit = limit;
+        self
+    }
+
+    /// Queue an input.
+    pub fn input(&mut self, value: i64) {
+        self.inputs.push_back(value);
+    }
+
+    /// Queue inputs in order.
+    pub fn inputs(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.inputs.extend(values);
+    }
+
+    /// Number of inputs given but not read yet.
+    pub fn queued(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    /// Write a value, growing the memory up to its address, which must be below the memory limit.
+    pub fn write(&mut self, address: usize, value: i64) -> Result<()> {
+        if self.memory_limit.is_some_and(|limit| address >= limit) {
+            return Err(anyhow!("address out of memory at {}: {}", self.ip, address));
+        }
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+        Ok(())
+    }
+
+    /// Memory written so far, the rest being zero.
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    /// Address of the next instruction.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.base
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Run until the machine needs an input, outputs a value or halts.
+    pub fn run(&mut self) -> Result<State> {
+        loop {
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Run until the machine halts, returning its outputs, every input being given beforehand.
+    pub fn run_to_end(&mut self) -> Result<Vec<i64>> {
+        let mut outputs = Vec::new();
+        loop {
+            match self.run()? {
+                State::Output(value) => outputs.push(value),
+                State::Halted => return Ok(outputs),
+                State::Input => return Err(anyhow!("missing input at {}", self.ip)),
+            }
+        }
+    }
+
+    /// Run a single instruction, returning the state the machine stopped in, if any.
+    pub fn step(&mut self) -> Result<Option<State>> {
+        if self.halted {
+            return Ok(Some(State::Halted));
+        }
+        let instruction = self.instruction()?;
+        let mut next = self.ip + instruction.size();
+        let mut state = None;
+        match instruction.opcode {
+            Opcode::Add | Opcode::Multiply => {
+                let (a, b) = (self.load(&instruction, 0)?, self.load(&instruction, 1)?);
+                let (value, operator) = match instruction.opcode {
+                    Opcode::Add => (a.checked_add(b), '+'),
+                    _ => (a.checked_mul(b), '*'),
+                };
+                let value = value.ok_or_else(|| anyhow!("overflow at {}: {} {} {}", self.ip, a, operator, b))?;
+                self.store(&instruction, 2, value)?
+            }
+            Opcode::Input => match self.inputs.pop_front() {
+                Some(value) => self.store(&instruction, 0, value)?,
+                None => return Ok(Some(State::Input)),
+            },
+            Opcode::Output => state = Some(State::Output(self.load(&instruction, 0)?)),
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                if (self.load(&instruction, 0)? != 0) == (instruction.opcode == Opcode::JumpIfTrue) {
+                    let target = self.load(&instruction, 1)?;
+                    next = usize::try_from(target).map_err(|_| anyhow!("invalid jump target at {}: {}", self.ip, target))?;
+                }
+            }
+            Opcode::LessThan => self.store(&instruction, 2, (self.load(&instruction, 0)? < self.load(&instruction, 1)?) as i64)?,
+            Opcode::Equals => self.store(&instruction, 2, (self.load(&instruction, 0)? == self.load(&instruction, 1)?) as i64)?,
+            Opcode::AdjustBase => {
+                let offset = self.load(&instruction, 0)?;
+                self.base = self.base.checked_add(offset).ok_or_else(|| anyhow!("overflow at {}: rb={} + {}", self.ip, self.base, offset))?;
+            }
+            Opcode::Halt => {
+                self.halted = true;
+                return Ok(Some(State::Halted));
+            }
+        }
+        self.ip = next;
+        Ok(state)
+    }
+
+    /// Instruction at the instruction pointer.
+    pub fn instruction(&self) -> Result<Instruction> {
+        Instruction::decode(self.read(self.ip)).with_context(|| format!("at {}", self.ip))
+    }
+
+    /// Address of a parameter read or written by reference.
+    pub(crate) fn address(&self, instruction: &Instruction, parameter: usize) -> Result<usize> {
+        let value = self.read(self.ip + 1 + parameter);
+        let address = match instruction.modes[parameter] {
+            Mode::Position => value,
+            Mode::Relative => self.base.checked_add(value).ok_or_else(|| anyhow!("overflow at {}: rb={} + {}", self.ip, self.base, value))?,
+            Mode::Immediate => return Err(anyhow!("no address in immediate mode at {}", self.ip)),
+        };
+        usize::try_from(address).map_err(|_| anyhow!("invalid address at {}: {}", self.ip, address))
+    }
+
+    /// Value of a parameter.
+    pub(crate) fn load(&self, instruction: &Instruction, parameter: usize) -> Result<i64> {
+        match instruction.modes[parameter] {
+            Mode::Immediate => Ok(self.read(self.ip + 1 + parameter)),
+            _ => Ok(self.read(self.address(instruction, parameter)?)),
+        }
+    }
+
+    fn store(&mut self, instruction: &Instruction, parameter: usize, value: i64) -> Result<()> {
+        let address = self.address(instruction, parameter)?;
+        self.write(address, value)
+    }
+}
+
+#[cfg(test)]
+fn outputs(program: &str, inputs: &[i64]) -> Result<Vec<i64>> {
+    let mut machine = Machine::new(&crate::parse(program)?);
+    machine.inputs(inputs.iter().copied());
+    machine.run_to_end()
+}
+
+#[test]
+fn arithmetic() -> Result<()> {
+    let mut machine = Machine::new(&crate::parse("1,9,10,3,2,3,11,0,99,30,40,50")?);
+    assert_eq!(machine.run()?, State::Halted);
+    assert_eq!(machine.memory(), [3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]);
+    let mut machine = Machine::new(&crate::parse("1002,4,3,4,33")?);
+    machine.run()?;
+    assert_eq!(machine.read(4), 99);
+    let mut machine = Machine::new(&crate::parse("1101,100,-1,4,0")?);
+    machine.run()?;
+    assert_eq!(machine.read(4), 99);
+    Ok(())
+}
+
+#[test]
+fn comparisons() -> Result<()> {
+    for (program, less, equal, more) in [
+        ("3,9,8,9,10,9,4,9,99,-1,8", 0, 1, 0),
+        ("3,9,7,9,10,9,4,9,99,-1,8", 1, 0, 0),
+        ("3,3,1108,-1,8,3,4,3,99", 0, 1, 0),
+        ("3,3,1107,-1,8,3,4,3,99", 1, 0, 0),
+    ] {
+        assert_eq!(outputs(program, &[7])?, [less]);
+        assert_eq!(outputs(program, &[8])?, [equal]);
+        assert_eq!(outputs(program, &[9])?, [more]);
+    }
+    let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+                   999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+    assert_eq!(outputs(program, &[7])?, [999]);
+    assert_eq!(outputs(program, &[8])?, [1000]);
+    assert_eq!(outputs(program, &[9])?, [1001]);
+    Ok(())
+}
+
+#[test]
+fn relative_base() -> Result<()> {
+    let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    assert_eq!(outputs(quine, &[])?, crate::parse(quine)?);
+    assert_eq!(outputs("1102,34915192,34915192,7,4,7,99,0", &[])?, [1219070632396864]);
+    assert_eq!(outputs("104,1125899906842624,99", &[])?, [1125899906842624]);
+    Ok(())
+}
+
+#[test]
+fn pausing() -> Result<()> {
+    let mut machine = Machine::new(&crate::parse("3,0,4,0,3,0,4,0,99")?);
+    assert_eq!(machine.run()?, State::Input);
+    assert_eq!(machine.run()?, State::Input);
+    machine.input(5);
+    assert_eq!(machine.run()?, State::Output(5));
+    assert_eq!(machine.run()?, State::Input);
+    machine.input(-3);
+    assert_eq!(machine.run()?, State::Output(-3));
+    assert_eq!(machine.run()?, State::Halted);
+    assert!(machine.is_halted());
+    assert_eq!(machine.run()?, State::Halted);
+    assert!(outputs("3,0,99", &[]).is_err());
+    assert!(outputs("109,-5,204,0,99", &[]).is_err());
+    assert!(outputs("1101,1,1,4000000000,99", &[]).is_err());
+    assert!(outputs("1101,9223372036854775807,1,0,99", &[]).is_err());
+    assert!(outputs("1102,4611686018427387904,2,0,99", &[]).is_err());
+    assert!(outputs("109,9223372036854775807,109,1,99", &[]).is_err());
+    assert!(outputs("109,1,204,9223372036854775807,99", &[]).is_err());
+    let mut machine = Machine::new(&[99]);
+    machine.write(DEFAULT_MEMORY_LIMIT - 1, 1)?;
+    assert!(machine.write(DEFAULT_MEMORY_LIMIT, 1).is_err());
+    let mut machine = Machine::new(&[99]).with_memory_limit(Some(10));
+    machine.write(9, 1)?;
+    assert!(machine.write(10, 1).is_err());
+    let mut machine = Machine::new(&[99]).with_memory_limit(None);
+    machine.write(DEFAULT_MEMORY_LIMIT, 1)?;
+    assert_eq!(machine.read(DEFAULT_MEMORY_LIMIT), 1);
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use std::io::{BufRead, Write};
+
+use anyhow::{anyhow, Context, Result};
+use aoc::geom::Point2;
+use aoc::input;
+use aoc_2019_intcode::{Ascii, Machine, Screen, State, Tracer};
+
+const USAGE: &str = "\
+usage:
+    intcode disassemble <day|path>
+    intcode trace <day|path> [--input <values>] [--limit <steps>]
+    intcode ascii <day|path> [--script <path>] [--transcript <path>]
+    intcode render <day|path> [--input <values>] [--frames]
+
+A day loads the input of that day of 2019, values are comma-separated.
+The ascii command runs a program speaking text, with the lines of the script as first inputs, then those of the standard input.
+The render command draws the x, y, tile triples output by a program, tiles 0 to 4 being shown as ' #=-o',
+inputs being read from the standard input once those given are used, and with --frames prints the screen whenever an input is read.";
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let command = match args.next() {
+        Some(command) if matches!(command.as_str(), "disassemble" | "trace" | "ascii" | "render") => command,
+        Some(command) if matches!(command.as_str(), "help" | "--help" | "-h") => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        Some(command) => return Err(anyhow!("unknown command: '{}'\n\n{}", command, USAGE)),
+        None => return Err(anyhow!("missing command\n\n{}", USAGE)),
+    };
+    let mut program = None;
+    let mut inputs = Vec::new();
+    let mut limit = None;
+    let mut script = None;
+    let mut transcript = None;
+    let mut frames = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => inputs = aoc_2019_intcode::parse(&args.next().with_context(|| format!("missing value for '{}'", arg))?)?,
+            "--limit" => limit = Some(args.next().with_context(|| format!("missing value for '{}'", arg))?.parse::<u64>()?),
+            "--script" => script = Some(args.next().with_context(|| format!("missing value for '{}'", arg))?),
+            "--transcript" => transcript = Some(args.next().with_context(|| format!("missing value for '{}'", arg))?),
+            "--frames" => frames = true,
+            _ if program.is_none() && !arg.starts_with("--") => program = Some(load(&arg)?),
+            _ => return Err(anyhow!("unexpected argument: '{}'\n\n{}", arg, USAGE)),
+        }
+    }
+    let program = program.with_context(|| format!("missing program\n\n{}", USAGE))?;
+    match command.as_str() {
+        "disassemble" => {
+            print!("{}", aoc_2019_intcode::disassemble(&program));
+            Ok(())
+        }
+        "trace" => trace(&program, inputs, limit),
+        "ascii" => ascii(&program, script, transcript),
+        _ => render(&program, inputs, frames),
+    }
+}
+
+/// Program of a day of 2019, or of a file.
+fn load(source: &str) -> Result<Vec<i64>> {
+    let text = match source.parse::<u32>() {
+        Ok(day) => input::load(&aoc::workspace_root()?, 2019, day)?.with_context(|| format!("no input for day {}", day))?,
+        Err(_) => std::fs::read_to_string(source).with_context(|| format!("cannot read program '{}'", source))?,
+    };
+    aoc_2019_intcode::parse(&text)
+}
+
+fn trace(program: &[i64], inputs: Vec<i64>, limit: Option<u64>) -> Result<()> {
+    let mut machine = Machine::new(program);
+    machine.inputs(inputs);
+    let mut tracer = Tracer::new(std::io::stdout().lock());
+    while limit.is_none_or(|limit| tracer.steps() < limit) {
+        match tracer.step(&mut machine)? {
+            Some(State::Input) => return Err(anyhow!("missing input at {}", machine.ip())),
+            Some(State::Halted) => return Ok(()),
+            Some(State::Output(_)) | None => {}
+        }
+    }
+    std::io::stdout().flush()?;
+    eprintln!("stopped after {} steps", tracer.steps());
+    Ok(())
+}
+
+/// Run an ASCII program, echoing the lines of the script as if they were typed, except for `#` comments.
+fn ascii(program: &[i64], script: Option<String>, transcript: Option<String>) -> Result<()> {
+    let script = match &script {
+        Some(path) => std::fs::read_to_string(path).with_context(|| format!("cannot read script '{}'", path))?,
+        None => String::new(),
+    };
+    let mut script = script.lines().filter(|line| !line.starts_with('#'));
+    let mut stdin = std::io::stdin().lock().lines();
+    let mut ascii = Ascii::new(program).with_transcript();
+    loop {
+        let output = ascii.read()?;
+        print!("{}", output.text);
+        for value in output.values {
+            println!("{}", value);
+        }
+        if output.halted {
+            break;
+        }
+        let line = match script.next() {
+            Some(line) => {
+                println!("{}", line);
+                line.to_owned()
+            }
+            None => match stdin.next() {
+                Some(line) => line?,
+                None => break,
+            },
+        };
+        ascii.send(&line);
+    }
+    if let Some(path) = transcript {
+        std::fs::write(&path, ascii.transcript().unwrap_or_default()).with_context(|| format!("cannot write transcript '{}'", path))?;
+    }
+    Ok(())
+}
+
+/// Run a program drawing tiles, printing the screen at the end or as frames.
+fn render(program: &[i64], inputs: Vec<i64>, frames: bool) -> Result<()> {
+    let mut machine = Machine::new(program);
+    machine.inputs(inputs);
+    let mut stdin = std::io::stdin().lock().lines();
+    let mut screen = Screen::new();
+    // Values at negative positions, such as a score, shown under the screen.
+    let mut displays = Vec::new();
+    let mut outputs = Vec::new();
+    loop {
+        let state = machine.run()?;
+        if let State::Output(value) = state {
+            outputs.push(value);
+            continue;
+        }
+        screen.update(&outputs, |position, value| {
+            if position.x < 0 || position.y < 0 {
+                displays.retain(|&(other, _)| other != position);
+                displays.push((position, value));
+                return Ok(None);
+            }
+            Ok(Some(value))
+        })?;
+        outputs.clear();
+        let line = match state {
+            State::Input => {
+                if frames {
+                    print_screen(&screen, &displays);
+                }
+                match stdin.next() {
+                    Some(line) => line?,
+                    None => break,
+                }
+            }
+            _ => break,
+        };
+        machine.inputs(aoc_2019_intcode::parse(&line)?);
+    }
+    print_screen(&screen, &displays);
+    Ok(())
+}
+
+fn print_screen(screen: &Screen<i64>, displays: &[(Point2<i64>, i64)]) {
+    print!(
+        "{}",
+        screen.render(|tile| match tile {
+            Some(&tile @ 0..=4) => b" #=-o"[tile as usize] as char,
+            Some(_) => '?',
+            None => ' ',
+        })
+    );
+    for (position, value) in displays {
+        println!("{},{}: {}", position.x, position.y, value);
+    }
+}
@@ -0,0 +1,150 @@
+//! Machines running together, sending each other messages.
+
+use anyhow::{anyhow, Result};
+
+use crate::{Machine, State};
+
+/// Why a network stopped running.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Event {
+    /// Message a machine sent out of the network.
+    Message {
+        from: usize,
+        values: Vec<i64>,
+    },
+    /// Every machine waited for input for a whole round, without sending or receiving anything.
+    Idle,
+    Halted,
+}
+
+/// Machines run in turn, each until it waits for input, their outputs being grouped into messages of a fixed length
+/// and delivered to the inputs of other machines by a routing function.
+///
+/// The schedule is deterministic: machines always run in the same order, so runs can be reproduced.
+#[derive(Clone, Debug)]
+pub struct Network {
+    machines: Vec<Machine>,
+    outputs: Vec<Vec<i64>>,
+    message_len: usize,
+    idle_input: Option<i64>,
+    turn: Turn,
+    quiet: usize,
+}
+
+/// Turn of a machine.
+#[derive(Clone, Copy, Debug, Default)]
+struct Turn {
+    machine: usize,
+    started: bool,
+    /// Whether the machine received or sent anything.
+    active: bool,
+    /// Whether the machine was given the idle input.
+    fed: bool,
+}
+
+impl Network {
+    pub fn new(machines: Vec<Machine>, message_len: usize) -> Self {
+        Self {
+            outputs: vec![Vec::new(); machines.len()],
+            machines,
+            message_len: message_len.max(1),
+            idle_input: None,
+            turn: Turn::default(),
+            quiet: 0,
+        }
+    }
+
+    /// Input given to a machine waiting for one with none queued, once per turn, such as `-1` for "no packet".
+    pub fn with_idle_input(mut self, value: i64) -> Self {
+        self.idle_input = Some(value);
+        self
+    }
+
+    pub fn machines(&self) -> &[Machine] {
+        &self.machines
+    }
+
+    /// Queue inputs of a machine.
+    pub fn send(&mut self, to: usize, values: impl IntoIterator<Item = i64>) -> Result<()> {
+        let machine = self.machines.get_mut(to).ok_or_else(|| anyhow!("no machine {}", to))?;
+        machine.inputs(values);
+        Ok(())
+    }
+
+    /// Run until a message leaves the network, the network is idle or every machine halted.
+    ///
+    /// `route` gets the sender and values of every message, returning the machine to deliver it to and the values to deliver,
+    /// or `None` for the message to leave the network.
+    pub fn run(&mut self, mut route: impl FnMut(usize, &[i64]) -> Option<(usize, Vec<i64>)>) -> Result<Event> {
+        loop {
+            if self.machines.iter().all(Machine::is_halted) {
+                return Ok(Event::Halted);
+            }
+            if self.quiet >= self.machines.len() {
+                self.quiet = 0;
+                return Ok(Event::Idle);
+            }
+            let from = self.turn.machine;
+            let machine = &mut self.machines[from];
+            if !self.turn.started {
+                self.turn.started = true;
+                self.turn.active = machine.queued() > 0;
+            }
+            match machine.run()? {
+                State::Output(value) => {
+                    self.turn.active = true;
+                    self.outputs[from].push(value);
+                    if self.outputs[from].len() == self.message_len {
+                        let message = std::mem::take(&mut self.outputs[from]);
+                        match route(from, &message) {
+                            Some((to, values)) => self.send(to, values)?,
+                            None => return Ok(Event::Message { from, values: message }),
+                        }
+                    }
+                }
+                State::Input if !self.turn.fed && self.idle_input.is_some() => {
+                    machine.inputs(self.idle_input);
+                    self.turn.fed = true;
+                }
+                State::Input | State::Halted => {
+                    self.quiet = if self.turn.active { 0 } else { self.quiet + 1 };
+                    self.turn = Turn {
+                        machine: (from + 1) % self.machines.len(),
+                        ..Turn::default()
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn chain() -> Result<()> {
+    let echo = crate::parse("3,0,4,0,99")?;
+    let mut network = Network::new(vec![Machine::new(&echo); 3], 1);
+    network.send(0, [7])?;
+    let route = |from: usize, message: &[i64]| (from < 2).then(|| (from + 1, vec![message[0] * 2]));
+    assert_eq!(network.run(route)?, Event::Message { from: 2, values: vec![28] });
+    assert_eq!(network.run(route)?, Event::Halted);
+    assert!(network.send(3, [0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn idle() -> Result<()> {
+    // Read inputs until one is not negative, and output it.
+    let receiver = crate::parse("3,12,1007,12,0,13,1005,13,0,4,12,99,0,0")?;
+    let mut network = Network::new(vec![Machine::new(&receiver); 2], 1).with_idle_input(-1);
+    let route = |_: usize, _: &[i64]| None;
+    assert_eq!(network.run(route)?, Event::Idle);
+    assert_eq!(network.run(route)?, Event::Idle);
+    network.send(1, [5])?;
+    assert_eq!(network.run(route)?, Event::Message { from: 1, values: vec![5] });
+    assert_eq!(network.run(route)?, Event::Idle);
+    assert!(network.machines()[1].is_halted());
+
+    // Without an idle input, machines waiting for each other are idle too.
+    let mut network = Network::new(vec![Machine::new(&receiver); 2], 1);
+    assert_eq!(network.run(route)?, Event::Idle);
+    Ok(())
+}
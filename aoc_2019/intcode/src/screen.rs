@@ -0,0 +1,98 @@
+//! Pictures drawn by programs, as tiles at integer positions.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc::geom::Point2;
+
+/// Tiles drawn so far, on an unbounded plane whose `y` axis points down.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Screen<T> {
+    tiles: HashMap<Point2<i64>, T>,
+}
+
+impl<T> Default for Screen<T> {
+    fn default() -> Self {
+        Self { tiles: HashMap::new() }
+    }
+}
+
+impl<T> Screen<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn get(&self, position: Point2<i64>) -> Option<&T> {
+        self.tiles.get(&position)
+    }
+
+    /// Draw a tile, returning the one it replaces.
+    pub fn draw(&mut self, position: Point2<i64>, tile: T) -> Option<T> {
+        self.tiles.insert(position, tile)
+    }
+
+    /// Tiles drawn, in no particular order.
+    pub fn tiles(&self) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.tiles.iter().map(|(&position, tile)| (position, tile))
+    }
+
+    /// Draw output values given as `x, y, value` triples, `tile` turning a value into the tile at its position,
+    /// or into nothing for values not meant to be drawn, such as a score.
+    pub fn update(&mut self, values: &[i64], mut tile: impl FnMut(Point2<i64>, i64) -> Result<Option<T>>) -> Result<()> {
+        if !values.len().is_multiple_of(3) {
+            return Err(anyhow!("output of {} values is not made of x, y, tile triples", values.len()));
+        }
+        for triple in values.chunks(3) {
+            let position = Point2::new(triple[0], triple[1]);
+            if let Some(tile) = tile(position, triple[2])? {
+                self.tiles.insert(position, tile);
+            }
+        }
+        Ok(())
+    }
+
+    /// Top left and bottom right corners of the tiles drawn.
+    pub fn bounds(&self) -> Option<(Point2<i64>, Point2<i64>)> {
+        let mut positions = self.tiles.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), position| {
+            (Point2::new(min.x.min(position.x), min.y.min(position.y)), Point2::new(max.x.max(position.x), max.y.max(position.y)))
+        }))
+    }
+
+    /// Text picture of the tiles within the bounds, a line per row, `pixel` giving the character of a tile or of a blank.
+    pub fn render(&self, mut pixel: impl FnMut(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds() else { return String::new() };
+        let mut picture = String::new();
+        for y in min.y..=max.y {
+            picture.extend((min.x..=max.x).map(|x| pixel(self.get(Point2::new(x, y)))));
+            picture.push('\n');
+        }
+        picture
+    }
+}
+
+#[test]
+fn draw() -> Result<()> {
+    let mut screen = Screen::new();
+    assert_eq!(screen.render(|_| '#'), "");
+    screen.update(&[1, 0, 1, 3, 1, 2, -1, 0, 500], |position, value| Ok((position.x >= 0).then_some(value)))?;
+    assert_eq!(screen.len(), 2);
+    assert_eq!(screen.draw(Point2::new(1, 0), 3), Some(1));
+    assert_eq!(screen.bounds(), Some((Point2::new(1, 0), Point2::new(3, 1))));
+    let picture = screen.render(|tile| match tile {
+        Some(tile) => char::from_digit(*tile as u32, 10).unwrap_or('?'),
+        None => '.',
+    });
+    assert_eq!(picture, "3..\n..2\n");
+    assert!(screen.update(&[1, 2], |_, value| Ok(Some(value))).is_err());
+    Ok(())
+}
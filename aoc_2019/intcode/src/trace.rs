@@ -0,0 +1,115 @@
+//! Execution traces, a line per instruction run with the values of its operands.
+
+use std::io::Write;
+
+use anyhow::Result;
+
+use crate::{Machine, Mode, Opcode, State};
+
+/// Runs a machine like [`Machine::run`], writing each instruction as it is run.
+///
+/// Operands read from memory are followed by their value, as in `add [12]=5, 3 -> [12]=8`,
+/// and relative addresses by the address they resolve to, as in `[rb+2]@1002=0`.
+#[derive(Debug)]
+pub struct Tracer<W> {
+    out: W,
+    steps: u64,
+}
+
+impl<W: Write> Tracer<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, steps: 0 }
+    }
+
+    /// Number of instructions run so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn run(&mut self, machine: &mut Machine) -> Result<State> {
+        loop {
+            if let Some(state) = self.step(machine)? {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Run a single instruction like [`Machine::step`], an instruction waiting for input not being written.
+    pub fn step(&mut self, machine: &mut Machine) -> Result<Option<State>> {
+        if machine.is_halted() {
+            return Ok(Some(State::Halted));
+        }
+        let (ip, instruction) = (machine.ip(), machine.instruction()?);
+        let parameters = instruction.opcode.parameters();
+        // The written address is resolved beforehand, as the instruction may overwrite itself.
+        let written = if instruction.opcode.writes() {
+            Some((machine.read(ip + parameters), machine.address(&instruction, parameters - 1)?))
+        } else {
+            None
+        };
+        let mut operands = Vec::new();
+        for parameter in 0..parameters - written.is_some() as usize {
+            let value = machine.read(ip + 1 + parameter);
+            let operand = match instruction.modes[parameter] {
+                Mode::Immediate => value.to_string(),
+                mode => {
+                    let address = machine.address(&instruction, parameter)?;
+                    format!("{}={}", reference(mode, value, address), machine.read(address))
+                }
+            };
+            operands.push(operand);
+        }
+
+        let state = machine.step()?;
+        if state == Some(State::Input) {
+            return Ok(state);
+        }
+        self.steps += 1;
+        let mut line = format!("{:>6}: {:<4}", ip, instruction.opcode);
+        if !operands.is_empty() {
+            line.push_str(&format!(" {}", operands.join(", ")));
+        }
+        if let Some((value, address)) = written {
+            line.push_str(&format!(" -> {}={}", reference(instruction.modes[parameters - 1], value, address), machine.read(address)));
+        }
+        if instruction.opcode == Opcode::AdjustBase {
+            line.push_str(&format!(" -> rb={}", machine.relative_base()));
+        }
+        writeln!(self.out, "{}", line)?;
+        Ok(state)
+    }
+}
+
+/// Address of an operand, followed by the address it resolves to when relative.
+fn reference(mode: Mode, value: i64, address: usize) -> String {
+    match mode {
+        Mode::Relative => format!("{}@{}", mode.operand(value), address),
+        _ => mode.operand(value),
+    }
+}
+
+#[test]
+fn trace() -> Result<()> {
+    let mut machine = Machine::new(&crate::parse("3,11,1001,11,5,11,109,9,204,2,99,0")?);
+    let mut tracer = Tracer::new(Vec::new());
+    assert_eq!(tracer.run(&mut machine)?, State::Input);
+    assert!(tracer.out.is_empty());
+    machine.input(4);
+    assert_eq!(tracer.run(&mut machine)?, State::Output(9));
+    assert_eq!(tracer.run(&mut machine)?, State::Halted);
+    assert_eq!(tracer.steps(), 5);
+    let expected = [
+        "     0: in   -> [11]=4",
+        "     2: add  [11]=4, 5 -> [11]=9",
+        "     6: arb  9 -> rb=9",
+        "     8: out  [rb+2]@11=9",
+        "    10: halt",
+    ];
+    assert_eq!(String::from_utf8(tracer.out)?.lines().collect::<Vec<_>>(), expected);
+
+    let mut machine = Machine::new(&crate::parse("1,0,0,3,99")?);
+    let mut tracer = Tracer::new(Vec::new());
+    tracer.step(&mut machine)?;
+    assert_eq!(String::from_utf8(tracer.out)?, "     0: add  [0]=1, [0]=1 -> [3]=2\n");
+    Ok(())
+}
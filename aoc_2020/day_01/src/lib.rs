@@ -1,48 +1,57 @@
 use std::collections::HashSet;
 
 use anyhow::{anyhow, Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2020;
-pub const DAY: u32 = 1;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u32> {
-    let mut report = HashSet::new();
-    for expense in input.trim().lines() {
-        let expense = expense.parse::<u32>().with_context(|| format!("invalid expense: '{}", expense))?;
-        report.insert(expense);
-    }
-    for x in report.iter() {
-        if let Some(y) = report.get(&(2020 - x)) {
-            return Ok(x * y);
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    type Input = HashSet<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut report = HashSet::new();
+        for expense in input.trim().lines() {
+            let expense = expense.parse::<u32>().with_context(|| format!("invalid expense: '{}", expense))?;
+            report.insert(expense);
         }
+        Ok(report)
     }
-    Err(anyhow!("complement expenses not found"))
-}
 
-pub fn part_two(input: &str) -> Result<u32> {
-    let mut report = HashSet::new();
-    for expense in input.trim().lines() {
-        let expense = expense.parse::<u32>().with_context(|| format!("invalid expense: '{}", expense))?;
-        report.insert(expense);
+    fn part_one(report: &Self::Input) -> Result<Self::PartOne> {
+        for x in report.iter() {
+            if let Some(y) = 2020u32.checked_sub(*x).and_then(|y| report.get(&y)) {
+                return Ok(x * y);
+            }
+        }
+        Err(anyhow!("complement expenses not found"))
     }
-    for x in report.iter() {
-        for y in report.iter() {
-            if let Some(z) = report.get(&(2020 - x - y)) {
-                return Ok(x * y * z);
+
+    fn part_two(report: &Self::Input) -> Result<Self::PartTwo> {
+        for x in report.iter() {
+            for y in report.iter() {
+                if let Some(z) = 2020u32.checked_sub(x + y).and_then(|z| report.get(&z)) {
+                    return Ok(x * y * z);
+                }
             }
         }
+        Err(anyhow!("complement expenses not found"))
     }
-    Err(anyhow!("complement expenses not found"))
 }
 
 #[test]
 fn part_one_example() -> Result<()> {
-    assert_eq!(part_one("1721\n979\n366\n299\n675\n1456\n")?, 514579);
+    assert_eq!(Solver::part_one(&Solver::parse("1721\n979\n366\n299\n675\n1456\n")?)?, 514579);
     Ok(())
 }
 
 #[test]
 fn part_two_example() -> Result<()> {
-    assert_eq!(part_two("1721\n979\n366\n299\n675\n1456\n")?, 241861950);
+    assert_eq!(Solver::part_two(&Solver::parse("1721\n979\n366\n299\n675\n1456\n")?)?, 241861950);
     Ok(())
 }
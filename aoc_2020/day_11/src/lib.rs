@@ -1,12 +1,105 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    automaton::{Cycle, Dense},
+    grid::Grid,
+    Solution,
+};
 
-pub const YEAR: u32 = 2020;
-pub const DAY: u32 = 11;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Seating System";
+
+    type Input = Grid<Seat>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| match c {
+            '.' => Ok(Seat::Floor),
+            'L' => Ok(Seat::Empty),
+            '#' => Ok(Seat::Occupied),
+            _ => Err(anyhow!("invalid seat: '{}'", c)),
+        })
+    }
+
+    fn part_one(seats: &Self::Input) -> Result<Self::PartOne> {
+        Ok(settle(seats, &Dense::moore(seats), 4))
+    }
+
+    fn part_two(seats: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(settle(seats, &Dense::new(seats, visible), 5))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+/// First seat seen in each of the eight directions.
+fn visible(seats: &Grid<Seat>, x: usize, y: usize) -> Vec<(usize, usize)> {
+    let mut visible = Vec::new();
+    for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+        let (mut x, mut y) = (x, y);
+        while let (Some(next_x), Some(next_y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+            (x, y) = (next_x, next_y);
+            match seats.get(x, y) {
+                Some(Seat::Floor) => {}
+                Some(_) => {
+                    visible.push((x, y));
+                    break;
+                }
+                None => break,
+            }
+        }
+    }
+    visible
+}
+
+/// Number of occupied seats once nobody moves, people leaving when `tolerance` neighbours are occupied.
+fn settle(seats: &Grid<Seat>, dense: &Dense, tolerance: usize) -> usize {
+    let cycle = Cycle::find(seats.clone(), |seats| {
+        dense.step(seats, |_, &seat, neighbours| {
+            let occupied = neighbours.filter(|&&seat| seat == Seat::Occupied).count();
+            match seat {
+                Seat::Empty if occupied == 0 => Seat::Occupied,
+                Seat::Occupied if occupied >= tolerance => Seat::Empty,
+                seat => seat,
+            }
+        })
+    });
+    cycle.repeated().values().filter(|&&seat| seat == Seat::Occupied).count()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let seats = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_one(&seats)?, 37);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let seats = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_two(&seats)?, 26);
+    Ok(())
 }
@@ -1,12 +1,74 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{
+    automaton::{Rule, Sparse},
+    geom::{Point3, Point4},
+    Solution,
+};
 
-pub const YEAR: u32 = 2020;
-pub const DAY: u32 = 17;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    type Input = Vec<(i32, i32)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut active = Vec::new();
+        for (y, line) in (0..).zip(input.lines()) {
+            for (x, c) in (0..).zip(line.chars()) {
+                match c {
+                    '#' => active.push((x, y)),
+                    '.' => {}
+                    _ => return Err(anyhow!("invalid cube: '{}'", c)),
+                }
+            }
+        }
+        Ok(active)
+    }
+
+    fn part_one(active: &Self::Input) -> Result<Self::PartOne> {
+        let cubes = Sparse::new(active.iter().map(|&(x, y)| Point3::new(x, y, 0)));
+        Ok(boot(cubes, Point3::neighbours))
+    }
+
+    fn part_two(active: &Self::Input) -> Result<Self::PartTwo> {
+        let cubes = Sparse::new(active.iter().map(|&(x, y)| Point4::new(x, y, 0, 0)));
+        Ok(boot(cubes, Point4::neighbours))
+    }
+}
+
+/// Number of active cubes after the six cycles of the boot process.
+fn boot<C>(mut cubes: Sparse<C>, neighbours: fn(C) -> Vec<C>) -> usize
+where
+    C: Copy + Eq + std::hash::Hash,
+{
+    for _ in 0..6 {
+        cubes = cubes.step(neighbours, |active, count| Rule::LIFE.apply(active, count));
+    }
+    cubes.len()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+.#.
+..#
+###
+";
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let active = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_one(&active)?, 112);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let active = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_two(&active)?, 848);
+    Ok(())
 }
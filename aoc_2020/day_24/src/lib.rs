@@ -1,12 +1,105 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
+use aoc::{
+    automaton::{Rule, Sparse},
+    hex::{Hex, PointyDirection},
+    Solution,
+};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = 2020;
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Lobby Layout";
+
+    type Input = Vec<Vec<PointyDirection>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(PointyDirection::parse_path).collect()
+    }
 
-pub const YEAR: u32 = 2020;
-pub const DAY: u32 = 24;
+    fn part_one(paths: &Self::Input) -> Result<Self::PartOne> {
+        Ok(black_tiles(paths).len())
+    }
+
+    fn part_two(paths: &Self::Input) -> Result<Self::PartTwo> {
+        let rule: Rule = "B2/S12".parse()?;
+        let mut floor = Sparse::new(black_tiles(paths));
+        for _ in 0..100 {
+            floor = floor.step(Hex::neighbours, |black, count| rule.apply(black, count));
+        }
+        Ok(floor.len())
+    }
+}
+
+/// Tiles flipped an odd number of times.
+fn black_tiles(paths: &[Vec<PointyDirection>]) -> HashSet<Hex> {
+    let mut black = HashSet::new();
+    for path in paths {
+        let tile = path.iter().fold(Hex::default(), |hex, &direction| hex.step(direction));
+        if !black.insert(tile) {
+            black.remove(&tile);
+        }
+    }
+    black
+}
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+#[test]
+fn part_one_example1() -> Result<()> {
+    let input = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew
+";
+    assert_eq!(Solver::part_one(&Solver::parse(input)?)?, 10);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let input = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew
+";
+    assert_eq!(Solver::part_two(&Solver::parse(input)?)?, 2208);
+    Ok(())
 }
@@ -1,52 +1,61 @@
 use anyhow::{Context, Result};
+use aoc::Solution;
 
-pub const YEAR: u32 = 2021;
-pub const DAY: u32 = 1;
+pub struct Solver;
 
-pub fn part_one(input: &str) -> Result<u32> {
-    let mut report = Vec::new();
-    for line in input.trim().lines() {
-        let sweep = line.parse::<u32>().with_context(|| format!("invalid sonar sweep: '{}'", line))?;
-        report.push(sweep);
-    }
-    let mut increase = 0;
-    let mut iter = report.into_iter().peekable();
-    while let Some(sweep) = iter.next() {
-        match iter.peek() {
-            Some(peek) if *peek > sweep => increase += 1,
-            None => break,
-            _ => continue,
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut report = Vec::new();
+        for line in input.trim().lines() {
+            let sweep = line.parse::<u32>().with_context(|| format!("invalid sonar sweep: '{}'", line))?;
+            report.push(sweep);
         }
+        Ok(report)
     }
-    Ok(increase)
-}
 
-pub fn part_two(input: &str) -> Result<u32> {
-    let mut report = Vec::new();
-    for line in input.trim().lines() {
-        let sweep = line.parse::<u32>().with_context(|| format!("invalid sonar sweep: '{}'", line))?;
-        report.push(sweep);
+    fn part_one(report: &Self::Input) -> Result<Self::PartOne> {
+        let mut increase = 0;
+        let mut iter = report.iter().peekable();
+        while let Some(sweep) = iter.next() {
+            match iter.peek() {
+                Some(peek) if *peek > sweep => increase += 1,
+                None => break,
+                _ => continue,
+            }
+        }
+        Ok(increase)
     }
-    let mut increase = 0;
-    let mut iter = report.windows(3).peekable();
-    while let Some(window) = iter.next() {
-        match iter.peek() {
-            Some(peek) if peek.iter().sum::<u32>() > window.iter().sum() => increase += 1,
-            None => break,
-            _ => continue,
-        };
+
+    fn part_two(report: &Self::Input) -> Result<Self::PartTwo> {
+        let mut increase = 0;
+        let mut iter = report.windows(3).peekable();
+        while let Some(window) = iter.next() {
+            match iter.peek() {
+                Some(peek) if peek.iter().sum::<u32>() > window.iter().sum() => increase += 1,
+                None => break,
+                _ => continue,
+            };
+        }
+        Ok(increase)
     }
-    Ok(increase)
 }
 
 #[test]
 fn part_one_example() -> Result<()> {
-    assert_eq!(part_one("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n")?, 7);
+    assert_eq!(Solver::part_one(&Solver::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n")?)?, 7);
     Ok(())
 }
 
 #[test]
 fn part_two_example() -> Result<()> {
-    assert_eq!(part_two("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n")?, 5);
+    assert_eq!(Solver::part_two(&Solver::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n")?)?, 5);
     Ok(())
 }
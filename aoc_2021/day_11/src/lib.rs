@@ -1,12 +1,92 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use aoc::{automaton::Dense, grid::Grid, Solution};
 
-pub const YEAR: u32 = 2021;
-pub const DAY: u32 = 11;
+pub struct Solver;
 
-pub fn part_one(_: &str) -> Result<u32> {
-    Ok(1)
+impl Solution for Solver {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse(input, |c| match c.to_digit(10) {
+            Some(energy) => Ok(energy as u8),
+            None => Err(anyhow!("invalid energy level: '{}'", c)),
+        })
+    }
+
+    fn part_one(octopuses: &Self::Input) -> Result<Self::PartOne> {
+        let dense = Dense::moore(octopuses);
+        let mut octopuses = octopuses.clone();
+        Ok((0..100).map(|_| step(&dense, &mut octopuses)).sum())
+    }
+
+    fn part_two(octopuses: &Self::Input) -> Result<Self::PartTwo> {
+        let dense = Dense::moore(octopuses);
+        let mut octopuses = octopuses.clone();
+        let count = octopuses.width() * octopuses.height();
+        Ok((1..).find(|_| step(&dense, &mut octopuses) == count).unwrap_or_default())
+    }
+}
+
+/// Run a step, returning the number of octopuses that flashed.
+///
+/// Flashes spread within the step, so energy levels are raised one neighbour at a time rather than by a rule.
+fn step(dense: &Dense, octopuses: &mut Grid<u8>) -> usize {
+    let mut flashing = Vec::new();
+    for (position, energy) in octopuses.iter_mut() {
+        *energy += 1;
+        if *energy > 9 {
+            flashing.push(position);
+        }
+    }
+    let mut flashes = 0;
+    while let Some((x, y)) = flashing.pop() {
+        flashes += 1;
+        for (x, y) in dense.neighbours(x, y) {
+            let energy = &mut octopuses[(x, y)];
+            *energy += 1;
+            if *energy == 10 {
+                flashing.push((x, y));
+            }
+        }
+    }
+    for (_, energy) in octopuses.iter_mut() {
+        if *energy > 9 {
+            *energy = 0;
+        }
+    }
+    flashes
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+#[test]
+fn part_one_example1() -> Result<()> {
+    let octopuses = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_one(&octopuses)?, 1656);
+    Ok(())
 }
 
-pub fn part_two(_: &str) -> Result<u32> {
-    Ok(2)
+#[test]
+fn part_two_example1() -> Result<()> {
+    let octopuses = Solver::parse(EXAMPLE)?;
+    assert_eq!(Solver::part_two(&octopuses)?, 195);
+    Ok(())
 }
@@ -0,0 +1,203 @@
+use std::{collections::VecDeque, fmt, path::PathBuf, str::FromStr};
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc::{
+    http::{self, Client},
+    input,
+    session::Session,
+    setup,
+};
+
+/// Command line arguments of a subcommand.
+///
+/// Options are written `--name value` or `--name=value`, switches are bare `--name` flags.
+#[derive(Debug)]
+pub struct Args {
+    positionals: VecDeque<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>, switches: &[&str]) -> Result<Self> {
+        let mut positionals = VecDeque::new();
+        let mut options = Vec::new();
+        let mut iter = args.into_iter();
+        while let Some(arg) = iter.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                if let Some((name, value)) = name.split_once('=') {
+                    options.push((name.to_owned(), Some(value.to_owned())));
+                } else if switches.contains(&name) {
+                    options.push((name.to_owned(), None));
+                } else {
+                    let value = iter.next().with_context(|| format!("missing value for '--{}'", name))?;
+                    options.push((name.to_owned(), Some(value)));
+                }
+            } else {
+                positionals.push_back(arg);
+            }
+        }
+        Ok(Self { positionals, options })
+    }
+
+    /// Take the next positional argument.
+    pub fn positional<T>(&mut self) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        match self.positionals.pop_front() {
+            Some(arg) => Ok(Some(arg.parse().map_err(Into::into).with_context(|| format!("invalid argument: '{}'", arg))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Take a switch, returning whether it was given.
+    pub fn switch(&mut self, name: &str) -> bool {
+        let len = self.options.len();
+        self.options.retain(|(option, value)| option != name || value.is_some());
+        self.options.len() != len
+    }
+
+    /// Take the value of an option.
+    pub fn option<T>(&mut self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        let idx = match self.options.iter().position(|(option, _)| option == name) {
+            Some(idx) => idx,
+            None => return Ok(None),
+        };
+        let value = self.options.remove(idx).1.with_context(|| format!("missing value for '--{}'", name))?;
+        let value = value.parse().map_err(Into::into).with_context(|| format!("invalid value for '--{}': '{}'", name, value))?;
+        Ok(Some(value))
+    }
+
+    /// Take the selection of days: `--all`, `<year>` or `<year> <day>`.
+    pub fn selection(&mut self) -> Result<Selection> {
+        let all = self.switch("all");
+        let year = self.positional()?;
+        let day = self.positional()?;
+        match (all, year, day) {
+            (true, None, None) => Ok(Selection::All),
+            (false, Some(year), None) => Ok(Selection::Year(year)),
+            (false, Some(year), Some(day)) => Ok(Selection::Day(year, day)),
+            (true, _, _) => Err(anyhow!("'--all' cannot be combined with a year or a day")),
+            (false, None, _) => Err(anyhow!("missing year, or '--all'")),
+        }
+    }
+
+    /// Take the selection of days, every day being selected when none is given.
+    pub fn selection_or_all(&mut self) -> Result<Selection> {
+        if self.positionals.is_empty() {
+            self.switch("all");
+            return Ok(Selection::All);
+        }
+        self.selection()
+    }
+
+    /// Fail on any argument that was not taken.
+    pub fn finish(self) -> Result<()> {
+        if let Some(arg) = self.positionals.front() {
+            return Err(anyhow!("unexpected argument: '{}'", arg));
+        }
+        if let Some((name, _)) = self.options.first() {
+            return Err(anyhow!("unexpected option: '--{}'", name));
+        }
+        Ok(())
+    }
+}
+
+/// Days selected on the command line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Selection {
+    All,
+    Year(u32),
+    Day(u32, u32),
+}
+
+impl Selection {
+    pub fn contains(&self, year: u32, day: u32) -> bool {
+        match *self {
+            Selection::All => true,
+            Selection::Year(y) => y == year,
+            Selection::Day(y, d) => y == year && d == day,
+        }
+    }
+}
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selection::All => write!(f, "all years"),
+            Selection::Year(year) => write!(f, "{}", year),
+            Selection::Day(year, day) => write!(f, "{}-{:02}", year, day),
+        }
+    }
+}
+
+/// Where to read a puzzle input from.
+#[derive(Clone, Debug)]
+pub enum Input {
+    /// Input of the day in the inputs directory, downloaded when missing.
+    Default,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Input {
+    pub fn read(&self, year: u32, day: u32) -> Result<String> {
+        match self {
+            Input::Default => {
+                let root = aoc::workspace_root()?;
+                if let Some(input) = input::load(&root, year, day)? {
+                    return Ok(input);
+                }
+                let path = input::path(&root, year, day);
+                let fetch = || -> Result<String> {
+                    let session = Session::find()?;
+                    let client = Client::new(&http::base_url(), &session.cookie)?;
+                    eprintln!("fetching input of {}-{:02} to '{}'", year, day, path.display());
+                    setup::fetch_input(&root, &client, year, day)
+                };
+                fetch().with_context(|| format!("no input '{}'", path.display()))
+            }
+            Input::Stdin => aoc::input_from_stdin(),
+            Input::Path(path) => std::fs::read_to_string(path).with_context(|| format!("cannot read input '{}'", path.display())),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Input::Stdin),
+            _ => Ok(Input::Path(s.into())),
+        }
+    }
+}
+
+#[test]
+fn selection() -> Result<()> {
+    let args = |args: &[&str]| Args::parse(args.iter().map(|arg| arg.to_string()), &["all"]);
+    assert_eq!(args(&["--all"])?.selection()?, Selection::All);
+    assert_eq!(args(&["2015"])?.selection()?, Selection::Year(2015));
+    assert_eq!(args(&["2015", "7"])?.selection()?, Selection::Day(2015, 7));
+    assert!(args(&["--all", "2015"])?.selection().is_err());
+    assert!(args(&[])?.selection().is_err());
+    Ok(())
+}
+
+#[test]
+fn options() -> Result<()> {
+    use aoc::Part;
+
+    let mut args = Args::parse(["2015", "--part", "2", "--input=-"].map(String::from), &[])?;
+    assert_eq!(args.option("part")?, Some(Part::Two));
+    assert!(matches!(args.option("input")?, Some(Input::Stdin)));
+    assert!(args.option::<Part>("part")?.is_none());
+    assert!(args.finish().is_err());
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use std::{
+    cmp::Reverse,
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc::DynSolution;
+
+use crate::{
+    args::{Args, Input},
+    history, registry,
+};
+
+/// Default history file, relative to the workspace root.
+const HISTORY: &str = "bench_history.csv";
+
+/// `aoc bench`: time parse, part one and part two of the selected days.
+pub fn bench(mut args: Args) -> Result<()> {
+    let selection = args.selection()?;
+    let warmup = args.option("warmup")?.unwrap_or(1);
+    let iterations = args.option("iterations")?.unwrap_or(10);
+    let sort = args.option("sort")?.unwrap_or(Sort::Day);
+    let record = args.switch("record");
+    let path: PathBuf = match args.option("history")? {
+        Some(path) => path,
+        None => aoc::workspace_root()?.join(HISTORY),
+    };
+    let threshold = args.option("threshold")?.unwrap_or(10.0);
+    args.finish()?;
+    if iterations == 0 {
+        return Err(anyhow!("'--iterations' must be at least 1"));
+    }
+    if threshold < 0.0 {
+        return Err(anyhow!("'--threshold' must not be negative"));
+    }
+    let mut timings = Vec::new();
+    for solution in registry::select(selection)? {
+        eprintln!("benchmarking {}-{:02}", solution.year(), solution.day());
+        timings.extend(measure(solution, &Input::Default, warmup, iterations)?);
+    }
+    sort.apply(&mut timings);
+    print_table(&timings);
+    let regressions = history::regressions(&history::load(&path)?, &timings, threshold);
+    if record {
+        history::append(&path, &timings)?;
+    }
+    if !regressions.is_empty() {
+        println!();
+        println!("regressions beyond {}% since the last recorded run:", threshold);
+        for regression in &regressions {
+            let Timing { year, day, step, stats } = regression.current;
+            println!(
+                "{}-{:02} {}: {} -> {} (+{:.1}%, recorded at {})",
+                year,
+                day,
+                step,
+                format_duration(regression.previous.timing.stats.median),
+                format_duration(stats.median),
+                regression.slowdown(),
+                regression.previous.commit
+            );
+        }
+        return Err(anyhow!("{} regressions", regressions.len()));
+    }
+    Ok(())
+}
+
+/// Time every step of a solution.
+pub fn measure(solution: &dyn DynSolution, input: &Input, warmup: usize, iterations: usize) -> Result<Vec<Timing>> {
+    let (year, day) = (solution.year(), solution.day());
+    let raw = input.read(year, day)?;
+    let parse = time(warmup, iterations, || solution.parse_input(&raw)).with_context(|| format!("{}-{:02}: invalid input", year, day))?;
+    let parsed = solution.parse_input(&raw)?;
+    let part_one = time(warmup, iterations, || solution.solve_part_one(parsed.as_ref())).with_context(|| format!("{}-{:02}: part one failed", year, day))?;
+    let part_two = time(warmup, iterations, || solution.solve_part_two(parsed.as_ref())).with_context(|| format!("{}-{:02}: part two failed", year, day))?;
+    let steps = [(Step::Parse, parse), (Step::PartOne, part_one), (Step::PartTwo, part_two)];
+    Ok(steps.into_iter().map(|(step, stats)| Timing { year, day, step, stats }).collect())
+}
+
+fn time<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..warmup {
+        f()?;
+    }
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        result?;
+    }
+    Ok(Stats::new(samples))
+}
+
+/// Timing of one step of a day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timing {
+    pub year: u32,
+    pub day: u32,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Step {
+    /// Name of the step in the history file.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::PartOne => "part_one",
+            Step::PartTwo => "part_two",
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            "part_one" => Ok(Step::PartOne),
+            "part_two" => Ok(Step::PartTwo),
+            _ => Err(anyhow!("invalid step: '{}'", s)),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::PartOne => write!(f, "part one"),
+            Step::PartTwo => write!(f, "part two"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let len = samples.len();
+        let min = samples[0];
+        let median = if len.is_multiple_of(2) { (samples[len / 2 - 1] + samples[len / 2]) / 2 } else { samples[len / 2] };
+        let mean = samples.iter().sum::<Duration>() / len as u32;
+        Self { min, median, mean }
+    }
+}
+
+/// Column to sort the table by, times are sorted slowest first.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Sort {
+    Day,
+    Min,
+    Median,
+    Mean,
+}
+
+impl Sort {
+    fn apply(&self, timings: &mut [Timing]) {
+        match self {
+            Sort::Day => timings.sort_by_key(|timing| (timing.year, timing.day, timing.step)),
+            Sort::Min => timings.sort_by_key(|timing| Reverse(timing.stats.min)),
+            Sort::Median => timings.sort_by_key(|timing| Reverse(timing.stats.median)),
+            Sort::Mean => timings.sort_by_key(|timing| Reverse(timing.stats.mean)),
+        }
+    }
+}
+
+impl FromStr for Sort {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Sort::Day),
+            "min" => Ok(Sort::Min),
+            "median" => Ok(Sort::Median),
+            "mean" => Ok(Sort::Mean),
+            _ => Err(anyhow!("invalid sort column: '{}'", s)),
+        }
+    }
+}
+
+fn print_table(timings: &[Timing]) {
+    println!("{:<8} {:<9} {:>11} {:>11} {:>11}", "day", "step", "min", "median", "mean");
+    for timing in timings {
+        println!(
+            "{:<8} {:<9} {:>11} {:>11} {:>11}",
+            format!("{}-{:02}", timing.year, timing.day),
+            timing.step.to_string(),
+            format_duration(timing.stats.min),
+            format_duration(timing.stats.median),
+            format_duration(timing.stats.mean),
+        );
+    }
+}
+
+/// Format a duration with a unit from ns to s.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.3} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.3} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.3} s", nanos as f64 / 1e9)
+    }
+}
+
+#[test]
+fn stats() {
+    let stats = Stats::new([4, 1, 3, 2].map(Duration::from_millis).to_vec());
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    let stats = Stats::new([5, 1, 3].map(Duration::from_millis).to_vec());
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.mean, Duration::from_millis(3));
+}
+
+#[test]
+fn duration() {
+    assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+    assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.500 µs");
+    assert_eq!(format_duration(Duration::from_micros(2_250)), "2.250 ms");
+    assert_eq!(format_duration(Duration::from_millis(3_125)), "3.125 s");
+}
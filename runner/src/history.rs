@@ -0,0 +1,209 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::OpenOptions,
+    io::Write,
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Context, Error, Result};
+
+use crate::bench::{Stats, Step, Timing};
+
+const HEADER: &str = "timestamp,commit,year,day,step,min_ns,median_ns,mean_ns";
+
+/// Benchmark timing recorded in the history file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    pub timestamp: u64,
+    pub commit: String,
+    pub timing: Timing,
+}
+
+impl Record {
+    fn key(&self) -> (u32, u32, Step) {
+        (self.timing.year, self.timing.day, self.timing.step)
+    }
+}
+
+impl FromStr for Record {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.split(',').collect();
+        let [timestamp, commit, year, day, step, min, median, mean] = fields[..] else {
+            return Err(anyhow!("invalid history record: '{}'", s));
+        };
+        let nanos = |field: &str| -> Result<Duration> { Ok(Duration::from_nanos(field.parse().with_context(|| format!("invalid duration: '{}'", field))?)) };
+        let stats = Stats {
+            min: nanos(min)?,
+            median: nanos(median)?,
+            mean: nanos(mean)?,
+        };
+        let timing = Timing {
+            year: year.parse().with_context(|| format!("invalid year: '{}'", year))?,
+            day: day.parse().with_context(|| format!("invalid day: '{}'", day))?,
+            step: step.parse()?,
+            stats,
+        };
+        let timestamp = timestamp.parse().with_context(|| format!("invalid timestamp: '{}'", timestamp))?;
+        Ok(Record {
+            timestamp,
+            commit: commit.to_owned(),
+            timing,
+        })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Timing { year, day, step, stats } = self.timing;
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            year,
+            day,
+            step.key(),
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos()
+        )
+    }
+}
+
+/// Load every record of a history file, a missing file being an empty history.
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(path).with_context(|| format!("cannot read history '{}'", path.display()))?;
+    contents.lines().filter(|line| !line.is_empty() && *line != HEADER).map(str::parse).collect()
+}
+
+/// Append timings to a history file, tagged with the current time and git commit.
+pub fn append(path: &Path, timings: &[Timing]) -> Result<()> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let commit = commit();
+    let new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("cannot open history '{}'", path.display()))?;
+    if new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for timing in timings {
+        let record = Record {
+            timestamp,
+            commit: commit.clone(),
+            timing: *timing,
+        };
+        writeln!(file, "{}", record)?;
+    }
+    Ok(())
+}
+
+/// Short hash of the checked out commit, suffixed with `-dirty` when the tree has changes.
+fn commit() -> String {
+    let output = match Command::new("git").args(["rev-parse", "--short", "HEAD"]).output() {
+        Ok(output) if output.status.success() => output,
+        _ => return "unknown".to_owned(),
+    };
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    match Command::new("git").args(["status", "--porcelain"]).output() {
+        Ok(status) if status.status.success() && !status.stdout.is_empty() => format!("{}-dirty", commit),
+        _ => commit,
+    }
+}
+
+/// Step whose median time grew beyond the threshold since its last recorded run.
+#[derive(Debug)]
+pub struct Regression {
+    pub previous: Record,
+    pub current: Timing,
+}
+
+impl Regression {
+    /// Median slowdown in percent, a previous median of zero counting as the nanosecond that timings are recorded in.
+    pub fn slowdown(&self) -> f64 {
+        let previous = self.previous.timing.stats.median.max(Duration::from_nanos(1)).as_secs_f64();
+        let current = self.current.stats.median.as_secs_f64();
+        (current / previous - 1.0) * 100.0
+    }
+}
+
+/// Compare timings against the last recorded run of each step, `threshold` being in percent.
+pub fn regressions(history: &[Record], timings: &[Timing], threshold: f64) -> Vec<Regression> {
+    let mut last = HashMap::new();
+    for record in history {
+        last.insert(record.key(), record);
+    }
+    let mut regressions = Vec::new();
+    for timing in timings {
+        if let Some(previous) = last.get(&(timing.year, timing.day, timing.step)) {
+            let limit = previous.timing.stats.median.as_secs_f64() * (1.0 + threshold / 100.0);
+            if timing.stats.median.as_secs_f64() > limit {
+                regressions.push(Regression {
+                    previous: (*previous).clone(),
+                    current: *timing,
+                });
+            }
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+fn timing(day: u32, step: Step, median: u64) -> Timing {
+    let median = Duration::from_millis(median);
+    Timing {
+        year: 2015,
+        day,
+        step,
+        stats: Stats { min: median, median, mean: median },
+    }
+}
+
+#[test]
+fn record() -> Result<()> {
+    let record = Record {
+        timestamp: 1640995200,
+        commit: "84cd914".to_owned(),
+        timing: timing(4, Step::PartTwo, 2194),
+    };
+    assert_eq!(record.to_string(), "1640995200,84cd914,2015,4,part_two,2194000000,2194000000,2194000000");
+    assert_eq!(record.to_string().parse::<Record>()?, record);
+    assert!("1640995200,84cd914,2015,4".parse::<Record>().is_err());
+    Ok(())
+}
+
+#[test]
+fn regression() -> Result<()> {
+    let record = |commit: &str, timing| Record {
+        timestamp: 0,
+        commit: commit.to_owned(),
+        timing,
+    };
+    let history = [
+        record("a", timing(4, Step::PartOne, 100)),
+        record("a", timing(6, Step::PartOne, 100)),
+        record("b", timing(4, Step::PartOne, 200)),
+    ];
+    let timings = [timing(4, Step::PartOne, 210), timing(6, Step::PartOne, 120), timing(6, Step::PartTwo, 500)];
+    let regressions = regressions(&history, &timings, 10.0);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].previous.commit, "a");
+    assert_eq!(regressions[0].current.day, 6);
+    assert!((regressions[0].slowdown() - 20.0).abs() < 1e-9);
+
+    let regressions = self::regressions(&[record("c", timing(6, Step::PartTwo, 0))], &timings, 10.0);
+    assert_eq!(regressions.len(), 1);
+    assert!((regressions[0].slowdown() - 49_999_999_900.0).abs() < 1e-3);
+    Ok(())
+}
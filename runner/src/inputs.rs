@@ -0,0 +1,47 @@
+use anyhow::{Context, Result};
+use aoc::input;
+
+use crate::{args::Args, registry};
+
+/// `aoc encrypt`: write an encrypted copy of the inputs of the selected days, with the passphrase in `AOC_INPUT_KEY`.
+pub fn encrypt(mut args: Args) -> Result<()> {
+    let selection = args.selection_or_all()?;
+    args.finish()?;
+    let passphrase = passphrase()?;
+    let root = aoc::workspace_root()?;
+    for solution in registry::select(selection)? {
+        let path = input::path(&root, solution.year(), solution.day());
+        if !path.exists() {
+            continue;
+        }
+        let plain = std::fs::read_to_string(&path).with_context(|| format!("cannot read input '{}'", path.display()))?;
+        let encrypted = input::encrypted_path(&path);
+        std::fs::write(&encrypted, input::encrypt(&plain, &passphrase)?).with_context(|| format!("cannot write input '{}'", encrypted.display()))?;
+        println!("{}-{:02}: encrypted to '{}'", solution.year(), solution.day(), encrypted.display());
+    }
+    Ok(())
+}
+
+/// `aoc decrypt`: restore the missing inputs of the selected days from their encrypted copy.
+pub fn decrypt(mut args: Args) -> Result<()> {
+    let selection = args.selection_or_all()?;
+    args.finish()?;
+    let passphrase = passphrase()?;
+    let root = aoc::workspace_root()?;
+    for solution in registry::select(selection)? {
+        let path = input::path(&root, solution.year(), solution.day());
+        let encrypted = input::encrypted_path(&path);
+        if path.exists() || !encrypted.exists() {
+            continue;
+        }
+        let data = std::fs::read(&encrypted).with_context(|| format!("cannot read input '{}'", encrypted.display()))?;
+        let plain = input::decrypt(&data, &passphrase).with_context(|| format!("invalid encrypted input '{}'", encrypted.display()))?;
+        std::fs::write(&path, plain).with_context(|| format!("cannot write input '{}'", path.display()))?;
+        println!("{}-{:02}: decrypted to '{}'", solution.year(), solution.day(), path.display());
+    }
+    Ok(())
+}
+
+fn passphrase() -> Result<String> {
+    std::env::var(input::KEY_ENV).with_context(|| format!("missing passphrase, set {}", input::KEY_ENV))
+}
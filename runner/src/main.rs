@@ -0,0 +1,41 @@
+mod args;
+mod bench;
+mod history;
+mod inputs;
+mod registry;
+mod run;
+mod submit;
+mod verify;
+
+use anyhow::{anyhow, Result};
+
+use crate::args::Args;
+
+const USAGE: &str = "\
+usage:
+    aoc run <year> [<day>] [--part <1|2>] [--input <path|->]
+    aoc run --all [--part <1|2>]
+    aoc bench (<year> [<day>] | --all) [--warmup <n>] [--iterations <n>] [--sort <day|min|median|mean>]
+        [--record] [--history <path>] [--threshold <percent>]
+    aoc verify [<year> [<day>] | --all]
+    aoc submit <year> <day> <1|2> [--log <path>]
+    aoc encrypt [<year> [<day>] | --all]
+    aoc decrypt [<year> [<day>] | --all]";
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("run") => run::run(Args::parse(args, &["all"])?),
+        Some("bench") => bench::bench(Args::parse(args, &["all", "record"])?),
+        Some("submit") => submit::submit(Args::parse(args, &[])?),
+        Some("verify") => verify::verify(Args::parse(args, &["all"])?),
+        Some("encrypt") => inputs::encrypt(Args::parse(args, &["all"])?),
+        Some("decrypt") => inputs::decrypt(Args::parse(args, &["all"])?),
+        Some("help" | "--help" | "-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(anyhow!("unknown command: '{}'\n\n{}", command, USAGE)),
+        None => Err(anyhow!("missing command\n\n{}", USAGE)),
+    }
+}
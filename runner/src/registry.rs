@@ -0,0 +1,185 @@
+use anyhow::{anyhow, Result};
+use aoc::DynSolution;
+
+use crate::args::Selection;
+
+/// Every day crate of the workspace, sorted by year and day.
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &aoc_2015_day_01::Solver,
+    &aoc_2015_day_02::Solver,
+    &aoc_2015_day_03::Solver,
+    &aoc_2015_day_04::Solver,
+    &aoc_2015_day_05::Solver,
+    &aoc_2015_day_06::Solver,
+    &aoc_2015_day_07::Solver,
+    &aoc_2015_day_08::Solver,
+    &aoc_2015_day_09::Solver,
+    &aoc_2015_day_10::Solver,
+    &aoc_2015_day_11::Solver,
+    &aoc_2015_day_12::Solver,
+    &aoc_2015_day_13::Solver,
+    &aoc_2015_day_14::Solver,
+    &aoc_2015_day_15::Solver,
+    &aoc_2015_day_16::Solver,
+    &aoc_2015_day_17::Solver,
+    &aoc_2015_day_18::Solver,
+    &aoc_2015_day_19::Solver,
+    &aoc_2015_day_20::Solver,
+    &aoc_2015_day_21::Solver,
+    &aoc_2015_day_22::Solver,
+    &aoc_2015_day_23::Solver,
+    &aoc_2015_day_24::Solver,
+    &aoc_2015_day_25::Solver,
+    &aoc_2016_day_01::Solver,
+    &aoc_2016_day_02::Solver,
+    &aoc_2016_day_03::Solver,
+    &aoc_2016_day_04::Solver,
+    &aoc_2016_day_05::Solver,
+    &aoc_2016_day_06::Solver,
+    &aoc_2016_day_07::Solver,
+    &aoc_2016_day_08::Solver,
+    &aoc_2016_day_09::Solver,
+    &aoc_2016_day_10::Solver,
+    &aoc_2016_day_11::Solver,
+    &aoc_2016_day_12::Solver,
+    &aoc_2016_day_13::Solver,
+    &aoc_2016_day_14::Solver,
+    &aoc_2016_day_15::Solver,
+    &aoc_2016_day_16::Solver,
+    &aoc_2016_day_17::Solver,
+    &aoc_2016_day_18::Solver,
+    &aoc_2016_day_19::Solver,
+    &aoc_2016_day_20::Solver,
+    &aoc_2016_day_21::Solver,
+    &aoc_2016_day_22::Solver,
+    &aoc_2016_day_23::Solver,
+    &aoc_2016_day_24::Solver,
+    &aoc_2016_day_25::Solver,
+    &aoc_2017_day_01::Solver,
+    &aoc_2017_day_02::Solver,
+    &aoc_2017_day_03::Solver,
+    &aoc_2017_day_04::Solver,
+    &aoc_2017_day_05::Solver,
+    &aoc_2017_day_06::Solver,
+    &aoc_2017_day_07::Solver,
+    &aoc_2017_day_08::Solver,
+    &aoc_2017_day_09::Solver,
+    &aoc_2017_day_10::Solver,
+    &aoc_2017_day_11::Solver,
+    &aoc_2017_day_12::Solver,
+    &aoc_2017_day_13::Solver,
+    &aoc_2017_day_14::Solver,
+    &aoc_2017_day_15::Solver,
+    &aoc_2017_day_16::Solver,
+    &aoc_2017_day_17::Solver,
+    &aoc_2017_day_18::Solver,
+    &aoc_2017_day_19::Solver,
+    &aoc_2017_day_20::Solver,
+    &aoc_2017_day_21::Solver,
+    &aoc_2017_day_22::Solver,
+    &aoc_2017_day_23::Solver,
+    &aoc_2017_day_24::Solver,
+    &aoc_2017_day_25::Solver,
+    &aoc_2018_day_01::Solver,
+    &aoc_2018_day_02::Solver,
+    &aoc_2018_day_03::Solver,
+    &aoc_2018_day_04::Solver,
+    &aoc_2018_day_05::Solver,
+    &aoc_2018_day_06::Solver,
+    &aoc_2018_day_07::Solver,
+    &aoc_2018_day_08::Solver,
+    &aoc_2018_day_09::Solver,
+    &aoc_2018_day_10::Solver,
+    &aoc_2018_day_11::Solver,
+    &aoc_2018_day_12::Solver,
+    &aoc_2018_day_13::Solver,
+    &aoc_2018_day_14::Solver,
+    &aoc_2018_day_15::Solver,
+    &aoc_2018_day_16::Solver,
+    &aoc_2018_day_17::Solver,
+    &aoc_2018_day_18::Solver,
+    &aoc_2018_day_19::Solver,
+    &aoc_2018_day_20::Solver,
+    &aoc_2018_day_21::Solver,
+    &aoc_2018_day_22::Solver,
+    &aoc_2018_day_23::Solver,
+    &aoc_2018_day_24::Solver,
+    &aoc_2018_day_25::Solver,
+    &aoc_2019_day_01::Solver,
+    &aoc_2019_day_02::Solver,
+    &aoc_2019_day_03::Solver,
+    &aoc_2019_day_04::Solver,
+    &aoc_2019_day_05::Solver,
+    &aoc_2019_day_06::Solver,
+    &aoc_2019_day_07::Solver,
+    &aoc_2019_day_08::Solver,
+    &aoc_2019_day_09::Solver,
+    &aoc_2019_day_10::Solver,
+    &aoc_2019_day_11::Solver,
+    &aoc_2019_day_12::Solver,
+    &aoc_2019_day_13::Solver,
+    &aoc_2019_day_14::Solver,
+    &aoc_2019_day_15::Solver,
+    &aoc_2019_day_16::Solver,
+    &aoc_2019_day_17::Solver,
+    &aoc_2019_day_18::Solver,
+    &aoc_2019_day_19::Solver,
+    &aoc_2019_day_20::Solver,
+    &aoc_2019_day_21::Solver,
+    &aoc_2019_day_22::Solver,
+    &aoc_2019_day_23::Solver,
+    &aoc_2019_day_24::Solver,
+    &aoc_2019_day_25::Solver,
+    &aoc_2020_day_01::Solver,
+    &aoc_2020_day_02::Solver,
+    &aoc_2020_day_03::Solver,
+    &aoc_2020_day_04::Solver,
+    &aoc_2020_day_05::Solver,
+    &aoc_2020_day_06::Solver,
+    &aoc_2020_day_07::Solver,
+    &aoc_2020_day_08::Solver,
+    &aoc_2020_day_09::Solver,
+    &aoc_2020_day_10::Solver,
+    &aoc_2020_day_11::Solver,
+    &aoc_2020_day_12::Solver,
+    &aoc_2020_day_13::Solver,
+    &aoc_2020_day_14::Solver,
+    &aoc_2020_day_15::Solver,
+    &aoc_2020_day_16::Solver,
+    &aoc_2020_day_17::Solver,
+    &aoc_2020_day_18::Solver,
+    &aoc_2020_day_19::Solver,
+    &aoc_2020_day_20::Solver,
+    &aoc_2020_day_21::Solver,
+    &aoc_2020_day_22::Solver,
+    &aoc_2020_day_23::Solver,
+    &aoc_2020_day_24::Solver,
+    &aoc_2020_day_25::Solver,
+    &aoc_2021_day_01::Solver,
+    &aoc_2021_day_02::Solver,
+    &aoc_2021_day_03::Solver,
+    &aoc_2021_day_04::Solver,
+    &aoc_2021_day_05::Solver,
+    &aoc_2021_day_06::Solver,
+    &aoc_2021_day_07::Solver,
+    &aoc_2021_day_08::Solver,
+    &aoc_2021_day_09::Solver,
+    &aoc_2021_day_10::Solver,
+    &aoc_2021_day_11::Solver,
+    &aoc_2021_day_12::Solver,
+    &aoc_2021_day_13::Solver,
+    &aoc_2021_day_14::Solver,
+    &aoc_2021_day_15::Solver,
+    &aoc_2021_day_16::Solver,
+    &aoc_2021_day_17::Solver,
+    &aoc_2021_day_18::Solver,
+];
+
+/// Solutions matching a selection, failing when there are none.
+pub fn select(selection: Selection) -> Result<Vec<&'static dyn DynSolution>> {
+    let solutions: Vec<_> = SOLUTIONS.iter().copied().filter(|solution| selection.contains(solution.year(), solution.day())).collect();
+    if solutions.is_empty() {
+        return Err(anyhow!("no solution for {}", selection));
+    }
+    Ok(solutions)
+}
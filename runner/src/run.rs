@@ -0,0 +1,36 @@
+use anyhow::{anyhow, Context, Result};
+use aoc::Part;
+
+use crate::{
+    args::{Args, Input},
+    registry,
+};
+
+/// `aoc run`: solve the selected days and print their answers.
+pub fn run(mut args: Args) -> Result<()> {
+    let selection = args.selection()?;
+    let part: Option<Part> = args.option("part")?;
+    let input = args.option("input")?.unwrap_or(Input::Default);
+    args.finish()?;
+    let solutions = registry::select(selection)?;
+    if solutions.len() > 1 && !matches!(input, Input::Default) {
+        return Err(anyhow!("'--input' requires a single day"));
+    }
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        println!("Advent of Code {}-{:02}: {}", year, day, solution.title());
+        let raw = input.read(year, day)?;
+        let parsed = solution.parse_input(&raw).with_context(|| format!("{}-{:02}: invalid input", year, day))?;
+        if part != Some(Part::Two) {
+            let answer = solution.solve_part_one(parsed.as_ref()).with_context(|| format!("{}-{:02}: part one failed", year, day))?;
+            println!("--> part one:");
+            println!("{}", answer);
+        }
+        if part != Some(Part::One) {
+            let answer = solution.solve_part_two(parsed.as_ref()).with_context(|| format!("{}-{:02}: part two failed", year, day))?;
+            println!("--> part two:");
+            println!("{}", answer);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Context, Result};
+use aoc::{
+    answers::Answers,
+    http::{self, Client},
+    session::Session,
+    submit::{self, Outcome, Submitter},
+    Part,
+};
+
+use crate::{
+    args::{Args, Input, Selection},
+    registry,
+};
+
+/// `aoc submit`: solve a part of a day and submit its answer.
+pub fn submit(mut args: Args) -> Result<()> {
+    let year = args.positional()?.context("missing year")?;
+    let day = args.positional()?.context("missing day")?;
+    let part: Part = args.positional()?.context("missing part")?;
+    let log: PathBuf = args.option("log")?.unwrap_or_else(|| PathBuf::from(submit::LOG));
+    args.finish()?;
+    let solution = registry::select(Selection::Day(year, day))?[0];
+    let raw = Input::Default.read(year, day)?;
+    let parsed = solution.parse_input(&raw).with_context(|| format!("{}-{:02}: invalid input", year, day))?;
+    let answer = solution.solve(parsed.as_ref(), part).with_context(|| format!("{}-{:02}: part {} failed", year, day, part))?;
+    println!("{}-{:02} part {}: submitting {}", year, day, part, answer);
+    let session = Session::find()?;
+    let client = Client::new(&http::base_url(), &session.cookie)?;
+    session.validate(&client)?;
+    let answers = Answers::path()?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let verdict = Submitter::new(&client, &log, &answers)?.submit(year, day, part, &answer, now)?;
+    println!("--> {}", verdict.outcome);
+    if verdict.wait > 0 {
+        println!("wait {}s before the next submission", verdict.wait);
+    }
+    if verdict.outcome == Outcome::Correct {
+        println!("recorded in {}", answers.display());
+        return Ok(());
+    }
+    Err(anyhow!("{}-{:02} part {}: {}", year, day, part, verdict.outcome))
+}
@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use aoc::{answers::Answers, input, DynSolution, Part};
+
+use crate::{args::Args, registry};
+
+/// `aoc verify`: check the selected days against `answers.toml`.
+pub fn verify(mut args: Args) -> Result<()> {
+    let selection = args.selection_or_all()?;
+    args.finish()?;
+    let answers = Answers::find()?;
+    let root = aoc::workspace_root()?;
+    let (mut verified, mut skipped, mut missing, mut failed) = (0, 0, 0, 0);
+    for solution in registry::select(selection)? {
+        let (year, day) = (solution.year(), solution.day());
+        let parts: Vec<_> = [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| answers.get(year, day, part).map(|answer| (part, answer)))
+            .collect();
+        skipped += 2 - parts.len();
+        if parts.is_empty() {
+            continue;
+        }
+        let input = match input::load(&root, year, day)? {
+            Some(input) => input,
+            None => {
+                println!("{}-{:02}: no input, skipping", year, day);
+                missing += parts.len();
+                continue;
+            }
+        };
+        for (part, expected) in parts {
+            match solve(solution, &input, part) {
+                Ok(answer) if answer == expected => {
+                    println!("{}-{:02} part {}: ok", year, day, part);
+                    verified += 1;
+                }
+                Ok(answer) => {
+                    println!("{}-{:02} part {}: expected {}, got {}", year, day, part, expected, answer);
+                    failed += 1;
+                }
+                Err(error) => {
+                    println!("{}-{:02} part {}: {:#}", year, day, part, error);
+                    failed += 1;
+                }
+            }
+        }
+    }
+    println!("{} verified, {} failed, {} without a recorded answer, {} without input", verified, failed, skipped, missing);
+    if failed > 0 {
+        return Err(anyhow!("{} answers failed verification", failed));
+    }
+    Ok(())
+}
+
+fn solve(solution: &dyn DynSolution, input: &str, part: Part) -> Result<String> {
+    let input = solution.parse_input(input)?;
+    solution.solve(input.as_ref(), part)
+}
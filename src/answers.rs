@@ -0,0 +1,180 @@
+//! Verified puzzle answers, recorded in `answers.toml` at the workspace root.
+//!
+//! The file holds one table per day, keyed by `<year>-<dd>`, with string values:
+//!
+//! ```toml
+//! [2015-01]
+//! part_one = "138"
+//! part_two = "1771"
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context, Error, Result};
+
+use crate::{input, Part, Solution};
+
+/// Name of the answers file.
+pub const FILE: &str = "answers.toml";
+
+/// Answers of every day, by year and day.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    days: BTreeMap<(u32, u32), BTreeMap<Part, String>>,
+}
+
+impl Answers {
+    /// Load the answers file of the workspace, looking up from the current directory.
+    pub fn find() -> Result<Self> {
+        Self::load(&Self::path()?)
+    }
+
+    /// Path of the answers file of the workspace, looking up from the current directory.
+    pub fn path() -> Result<PathBuf> {
+        Ok(crate::workspace_root()?.join(FILE))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).with_context(|| format!("cannot read answers '{}'", path.display()))?;
+        contents.parse().with_context(|| format!("invalid answers '{}'", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_string()).with_context(|| format!("cannot write answers '{}'", path.display()))
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: Part) -> Option<&str> {
+        self.days.get(&(year, day)).and_then(|day| day.get(&part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: Part, answer: impl Into<String>) {
+        self.days.entry((year, day)).or_default().insert(part, answer.into());
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut current = None;
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(key) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                let (year, day) = key.split_once('-').with_context(|| format!("line {}: invalid table: '{}'", idx + 1, line))?;
+                let year = year.parse().with_context(|| format!("line {}: invalid year: '{}'", idx + 1, year))?;
+                let day = day.parse().with_context(|| format!("line {}: invalid day: '{}'", idx + 1, day))?;
+                current = Some((year, day));
+                continue;
+            }
+            let (year, day) = current.with_context(|| format!("line {}: answer outside of a day table", idx + 1))?;
+            let (key, value) = line.split_once('=').with_context(|| format!("line {}: invalid answer: '{}'", idx + 1, line))?;
+            let part = match key.trim() {
+                "part_one" => Part::One,
+                "part_two" => Part::Two,
+                key => return Err(anyhow!("line {}: invalid part: '{}'", idx + 1, key)),
+            };
+            let value = unquote(value.trim()).with_context(|| format!("line {}: invalid string: '{}'", idx + 1, value.trim()))?;
+            answers.insert(year, day, part, value);
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Verified puzzle answers, checked by `aoc verify` and the day crate tests.")?;
+        for ((year, day), parts) in &self.days {
+            writeln!(f)?;
+            writeln!(f, "[{}-{:02}]", year, day)?;
+            for (part, answer) in parts {
+                writeln!(f, "{} = \"{}\"", part.key(), answer.replace('\\', "\\\\").replace('"', "\\\""))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn unquote(value: &str) -> Option<String> {
+    let value = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut string = String::new();
+    let mut chars = value.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next()? {
+                '\\' => string.push('\\'),
+                '"' => string.push('"'),
+                _ => return None,
+            },
+            '"' => return None,
+            _ => string.push(char),
+        }
+    }
+    Some(string)
+}
+
+/// Check a part of a solution against its recorded answer, skipping it when no answer is recorded or the input is missing.
+pub fn check<S: Solution>(part: Part) -> Result<()> {
+    let root = crate::workspace_root()?;
+    let answers = Answers::load(&root.join(FILE))?;
+    let expected = match answers.get(S::YEAR, S::DAY, part) {
+        Some(expected) => expected,
+        None => {
+            eprintln!("{}-{:02} part {}: no recorded answer, skipping", S::YEAR, S::DAY, part);
+            return Ok(());
+        }
+    };
+    let input = match input::load(&root, S::YEAR, S::DAY)? {
+        Some(input) => input,
+        None => {
+            eprintln!("{}-{:02} part {}: no input, skipping", S::YEAR, S::DAY, part);
+            return Ok(());
+        }
+    };
+    let input = S::parse(&input)?;
+    let answer = match part {
+        Part::One => S::part_one(&input)?.to_string(),
+        Part::Two => S::part_two(&input)?.to_string(),
+    };
+    if answer != expected {
+        return Err(anyhow!("{}-{:02} part {}: expected {}, got {}", S::YEAR, S::DAY, part, expected, answer));
+    }
+    Ok(())
+}
+
+#[test]
+fn parse() -> Result<()> {
+    let answers: Answers = "\
+# comment
+
+[2015-01]
+part_one = \"138\"
+part_two = \"1771\"
+
+[2016-05]
+part_one = \"a\\\"b\\\\c\"
+"
+    .parse()?;
+    assert_eq!(answers.get(2015, 1, Part::One), Some("138"));
+    assert_eq!(answers.get(2015, 1, Part::Two), Some("1771"));
+    assert_eq!(answers.get(2016, 5, Part::One), Some("a\"b\\c"));
+    assert_eq!(answers.get(2016, 5, Part::Two), None);
+    assert_eq!(answers.to_string().parse::<Answers>()?, answers);
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    assert!("part_one = \"1\"".parse::<Answers>().is_err());
+    assert!("[2015]\npart_one = \"1\"".parse::<Answers>().is_err());
+    assert!("[2015-01]\npart_three = \"1\"".parse::<Answers>().is_err());
+    assert!("[2015-01]\npart_one = 1".parse::<Answers>().is_err());
+}
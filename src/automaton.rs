@@ -0,0 +1,279 @@
+//! Cellular automata.
+//!
+//! [`Sparse`] keeps the live cells of an unbounded space in a set, cells being anything hashable such as
+//! points of any dimension, while [`Dense`] updates every cell of a [`Grid`], whatever its states.
+//! Both take their neighbourhood and their rule as closures, [`Rule`] covering the usual life-like rules,
+//! and [`Cycle`] finds when the generations repeat.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Error, Result};
+
+use crate::grid::Grid;
+
+/// Life-like rule, in `B3/S23` notation: the neighbour counts for which a cell is born and survives.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rule {
+    born: u32,
+    survive: u32,
+}
+
+impl Rule {
+    /// Conway's Game of Life.
+    pub const LIFE: Rule = Rule::new(&[3], &[2, 3]);
+
+    /// Rule from the neighbour counts for which a cell is born and survives, each below 32.
+    pub const fn new(born: &[usize], survive: &[usize]) -> Self {
+        const fn mask(counts: &[usize]) -> u32 {
+            let (mut mask, mut i) = (0, 0);
+            while i < counts.len() {
+                mask |= 1 << counts[i];
+                i += 1;
+            }
+            mask
+        }
+        Rule {
+            born: mask(born),
+            survive: mask(survive),
+        }
+    }
+
+    /// Whether a cell is alive in the next generation.
+    pub fn apply(&self, alive: bool, neighbours: usize) -> bool {
+        let counts = if alive { self.survive } else { self.born };
+        neighbours < 32 && counts & 1 << neighbours != 0
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let counts = |s: Option<&str>, prefix| {
+            let digits = s.and_then(|s| s.strip_prefix(prefix)).ok_or_else(|| anyhow!("invalid rule: '{}'", s.unwrap_or_default()))?;
+            digits.chars().try_fold(0, |counts, digit| match digit.to_digit(10) {
+                Some(count) => Ok(counts | 1 << count),
+                None => Err(anyhow!("invalid rule: '{}'", digits)),
+            })
+        };
+        let mut parts = s.split('/');
+        let rule = Rule {
+            born: counts(parts.next(), 'B')?,
+            survive: counts(parts.next(), 'S')?,
+        };
+        match parts.next() {
+            Some(_) => Err(anyhow!("invalid rule: '{}'", s)),
+            None => Ok(rule),
+        }
+    }
+}
+
+/// Live cells of an unbounded space.
+///
+/// Only cells next to a live one are considered, so rules must not give birth to cells without live neighbours.
+#[derive(Clone, Debug, Default)]
+pub struct Sparse<C> {
+    live: HashSet<C>,
+}
+
+impl<C: Copy + Eq + Hash> Sparse<C> {
+    pub fn new(live: impl IntoIterator<Item = C>) -> Self {
+        Self { live: live.into_iter().collect() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.live.is_empty()
+    }
+
+    pub fn contains(&self, cell: &C) -> bool {
+        self.live.contains(cell)
+    }
+
+    /// Live cells, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = &C> {
+        self.live.iter()
+    }
+
+    /// Next generation, `rule` deciding from whether a cell is alive and its number of live neighbours.
+    pub fn step<I>(&self, neighbours: impl Fn(C) -> I, rule: impl Fn(bool, usize) -> bool) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let mut counts: HashMap<C, usize> = self.live.iter().map(|&cell| (cell, 0)).collect();
+        for &cell in &self.live {
+            for neighbour in neighbours(cell) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+        let live = counts.into_iter().filter(|&(cell, count)| rule(self.live.contains(&cell), count)).map(|(cell, _)| cell);
+        Self { live: live.collect() }
+    }
+}
+
+/// Neighbourhood of every cell of a grid, computed once for all generations.
+#[derive(Clone, Debug)]
+pub struct Dense {
+    width: usize,
+    neighbours: Vec<Vec<(usize, usize)>>,
+}
+
+impl Dense {
+    /// Neighbourhood given by a function of the position of the cell.
+    pub fn new<'a, T, I>(grid: &'a Grid<T>, neighbourhood: impl Fn(&'a Grid<T>, usize, usize) -> I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let width = grid.width();
+        let neighbours = (0..grid.height())
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| neighbourhood(grid, x, y).into_iter().collect())
+            .collect();
+        Self { width, neighbours }
+    }
+
+    /// The eight surrounding cells.
+    pub fn moore<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid, Grid::neighbours8)
+    }
+
+    /// The four orthogonally adjacent cells.
+    pub fn von_neumann<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid, Grid::neighbours4)
+    }
+
+    /// Positions of the neighbours of a cell.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours[y * self.width + x].iter().copied()
+    }
+
+    /// Next generation, `rule` deciding from the position of a cell, its state and its neighbours.
+    pub fn step<T>(&self, grid: &Grid<T>, rule: impl Fn((usize, usize), &T, Neighbours<'_, T>) -> T) -> Grid<T> {
+        Grid::from_fn(grid.width(), grid.height(), |x, y| {
+            let neighbours = Neighbours {
+                grid,
+                positions: self.neighbours[y * self.width + x].iter(),
+            };
+            rule((x, y), &grid[(x, y)], neighbours)
+        })
+    }
+}
+
+/// States of the neighbours of a cell.
+#[derive(Clone, Debug)]
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    positions: std::slice::Iter<'a, (usize, usize)>,
+}
+
+impl<'a, T> Iterator for Neighbours<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions.next().map(|&position| &self.grid[position])
+    }
+}
+
+/// Generations of an automaton up to the first one repeating an earlier one.
+#[derive(Clone, Debug)]
+pub struct Cycle<S> {
+    states: Vec<S>,
+    start: usize,
+}
+
+impl<S: Clone + Eq + Hash> Cycle<S> {
+    pub fn find(initial: S, mut step: impl FnMut(&S) -> S) -> Self {
+        let mut seen = HashMap::new();
+        let mut states = vec![initial];
+        loop {
+            let state = states.last().expect("no state");
+            if let Some(&start) = seen.get(state) {
+                states.pop();
+                return Self { states, start };
+            }
+            seen.insert(state.clone(), states.len() - 1);
+            let next = step(state);
+            states.push(next);
+        }
+    }
+
+    /// First generation of the cycle.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Number of generations in the cycle.
+    pub fn period(&self) -> usize {
+        self.states.len() - self.start
+    }
+
+    /// First state seen twice.
+    pub fn repeated(&self) -> &S {
+        &self.states[self.start]
+    }
+
+    /// State after any number of generations.
+    pub fn nth(&self, generation: usize) -> &S {
+        match generation.checked_sub(self.start) {
+            Some(offset) => &self.states[self.start + offset % self.period()],
+            None => &self.states[generation],
+        }
+    }
+}
+
+#[test]
+fn rules() -> Result<()> {
+    assert_eq!("B3/S23".parse::<Rule>()?, Rule::LIFE);
+    let rule: Rule = "B2/S12".parse()?;
+    assert!(rule.apply(false, 2) && !rule.apply(false, 1));
+    assert!(rule.apply(true, 1) && !rule.apply(true, 3));
+    assert!(!rule.apply(true, 40));
+    assert_eq!(rule, Rule::new(&[2], &[1, 2]));
+    assert!("B3".parse::<Rule>().is_err());
+    assert!("S23/B3".parse::<Rule>().is_err());
+    assert!("B3/S2x".parse::<Rule>().is_err());
+    Ok(())
+}
+
+#[test]
+fn sparse() {
+    use crate::geom::Point2;
+
+    let glider = Sparse::new([(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(|(x, y)| Point2::new(x, y)));
+    let mut life = glider.clone();
+    for _ in 0..4 {
+        life = life.step(Point2::neighbours, |alive, count| Rule::LIFE.apply(alive, count));
+    }
+    let moved: HashSet<_> = glider.cells().map(|&cell| cell + Point2::new(1, 1)).collect();
+    assert_eq!(life.cells().copied().collect::<HashSet<_>>(), moved);
+}
+
+#[test]
+fn dense() -> Result<()> {
+    let blinker: Grid<char> = "...\n###\n...".parse()?;
+    let dense = Dense::moore(&blinker);
+    assert_eq!(dense.neighbours(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1), (1, 1)]);
+    let step = |grid: &Grid<char>| {
+        dense.step(grid, |_, &cell, neighbours| {
+            let count = neighbours.filter(|&&neighbour| neighbour == '#').count();
+            if Rule::LIFE.apply(cell == '#', count) {
+                '#'
+            } else {
+                '.'
+            }
+        })
+    };
+    assert_eq!(step(&blinker).to_string(), ".#.\n.#.\n.#.\n");
+    let cycle = Cycle::find(blinker.clone(), step);
+    assert_eq!((cycle.start(), cycle.period()), (0, 2));
+    assert_eq!(cycle.repeated(), &blinker);
+    assert_eq!(cycle.nth(1_000_000_001), &step(&blinker));
+    Ok(())
+}
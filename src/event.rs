@@ -0,0 +1,63 @@
+//! Calendar of the events, whose puzzles unlock at midnight EST (UTC-5) each day of December.
+
+/// Year of the first event.
+pub const FIRST_YEAR: u32 = 2015;
+
+/// Number of puzzles of an event, down to 12 since 2025.
+pub fn days(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Year of the latest event started at `now`, in seconds since the epoch.
+pub fn latest(now: u64) -> u32 {
+    let (year, month, _) = date(now);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Whether a puzzle is unlocked at `now`, in seconds since the epoch.
+pub fn unlocked(year: u32, day: u32, now: u64) -> bool {
+    let (current, month, today) = date(now);
+    (1..=days(year)).contains(&day) && (year < current || (year == current && month == 12 && day <= today))
+}
+
+/// Date in UTC-5 of a time in seconds since the epoch, as year, month and day.
+fn date(now: u64) -> (u32, u32, u32) {
+    // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (now as i64 - 5 * 3600).div_euclid(86400) + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year as u32, month as u32, day as u32)
+}
+
+#[test]
+fn calendar() {
+    // 2021-12-01T04:59:59Z and 2021-12-01T05:00:00Z, around the unlock of 2021-01.
+    assert_eq!(date(1638334799), (2021, 11, 30));
+    assert_eq!(date(1638334800), (2021, 12, 1));
+    assert_eq!(latest(1638334799), 2020);
+    assert_eq!(latest(1638334800), 2021);
+    assert!(!unlocked(2021, 1, 1638334799));
+    assert!(unlocked(2021, 1, 1638334800));
+    assert!(!unlocked(2021, 2, 1638334800));
+    assert!(unlocked(2020, 25, 1638334800));
+    // 2024-02-29T12:00:00Z.
+    assert_eq!(date(1709208000), (2024, 2, 29));
+    assert_eq!(latest(1709208000), 2023);
+    assert_eq!(days(2024), 25);
+    assert_eq!(days(2025), 12);
+    assert!(!unlocked(2025, 13, 1798761600));
+}
@@ -0,0 +1,451 @@
+//! Points and directions on integer grids.
+//!
+//! The `y` axis points down, as rows of a [`Grid`](crate::grid::Grid): north is `y - 1`.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Error, Result};
+
+/// Integer type of the coordinates of a point.
+pub trait Coordinate: Copy + Default + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ONE: Self;
+
+    /// Sum, or `None` when out of the range of the type.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Difference, or `None` when out of the range of the type, such as below zero for unsigned types.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! coordinate {
+    ($($int:ty),*) => {
+        $(
+            impl Coordinate for $int {
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn abs_diff<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+macro_rules! point {
+    ($(#[$doc:meta])* $point:ident { $($field:ident),+ }) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $point<T> {
+            $(pub $field: T,)+
+        }
+
+        impl<T: Coordinate> $point<T> {
+            pub const fn new($($field: T),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Sum of the absolute differences of the coordinates.
+            pub fn manhattan(self, other: Self) -> T {
+                let mut distance = T::default();
+                $(distance = distance + abs_diff(self.$field, other.$field);)+
+                distance
+            }
+
+            /// Largest absolute difference of the coordinates.
+            pub fn chebyshev(self, other: Self) -> T {
+                let mut distance = T::default();
+                $(distance = distance.max(abs_diff(self.$field, other.$field));)+
+                distance
+            }
+
+            /// Every adjacent point, diagonals included, except those out of the range of the coordinates.
+            pub fn neighbours(self) -> Vec<Self> {
+                let mut points = vec![self];
+                $(
+                    points = points
+                        .into_iter()
+                        .flat_map(|point| {
+                            let below = point.$field.checked_sub(T::ONE).map(|$field| Self { $field, ..point });
+                            let above = point.$field.checked_add(T::ONE).map(|$field| Self { $field, ..point });
+                            [below, Some(point), above].into_iter().flatten()
+                        })
+                        .collect();
+                )+
+                points.retain(|&point| point != self);
+                points
+            }
+        }
+
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self::Output {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self::Output {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self::Output {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Coordinate + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        /// Coordinates separated by commas, such as `3,-4`.
+        impl<T: fmt::Display> fmt::Display for $point<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coordinates = [$(self.$field.to_string()),+];
+                write!(f, "{}", coordinates.join(","))
+            }
+        }
+
+        impl<T: FromStr> FromStr for $point<T> {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut coordinates = s.split(',').map(|coordinate| coordinate.trim().parse().map_err(|_| anyhow!("invalid coordinate: '{}'", coordinate)));
+                let point = Self { $($field: coordinates.next().ok_or_else(|| anyhow!("invalid point: '{}'", s))??),+ };
+                if coordinates.next().is_some() {
+                    return Err(anyhow!("invalid point: '{}'", s));
+                }
+                Ok(point)
+            }
+        }
+    };
+}
+
+point!(
+    /// Point on a plane.
+    Point2 { x, y }
+);
+point!(
+    /// Point in space.
+    Point3 { x, y, z }
+);
+point!(
+    /// Point in four dimensions.
+    Point4 { x, y, z, w }
+);
+
+impl<T: Coordinate> Point2<T> {
+    /// Adjacent point in a direction.
+    ///
+    /// Panics when out of the range of the coordinates, such as north of `y = 0` for unsigned ones: see [`Self::checked_step`].
+    pub fn step(self, direction: impl Into<Direction8>) -> Self {
+        self.walk(direction, T::ONE)
+    }
+
+    /// Point at a distance in a direction, diagonal moves changing both coordinates by the distance.
+    ///
+    /// Panics when out of the range of the coordinates: see [`Self::checked_walk`].
+    pub fn walk(self, direction: impl Into<Direction8>, distance: T) -> Self {
+        self.checked_walk(direction, distance).expect("point out of the range of its coordinates")
+    }
+
+    /// Adjacent point in a direction, or `None` when out of the range of the coordinates.
+    pub fn checked_step(self, direction: impl Into<Direction8>) -> Option<Self> {
+        self.checked_walk(direction, T::ONE)
+    }
+
+    /// Point at a distance in a direction, or `None` when out of the range of the coordinates.
+    pub fn checked_walk(self, direction: impl Into<Direction8>, distance: T) -> Option<Self> {
+        let (dx, dy) = direction.into().offset();
+        let shift = |value: T, delta: i8| match delta {
+            -1 => value.checked_sub(distance),
+            1 => value.checked_add(distance),
+            _ => Some(value),
+        };
+        Some(Self::new(shift(self.x, dx)?, shift(self.y, dy)?))
+    }
+
+    /// Adjacent points to the north, east, south and west, except those out of the range of the coordinates.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().filter_map(move |direction| self.checked_step(direction))
+    }
+}
+
+/// Turn by a right angle.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' | 'l' => Ok(Turn::Left),
+            'R' | 'r' => Ok(Turn::Right),
+            _ => Err(anyhow!("invalid turn: '{}'", c.escape_default())),
+        }
+    }
+}
+
+impl FromStr for Turn {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<char>() {
+            Ok(c) => c.try_into(),
+            Err(_) => Err(anyhow!("invalid turn: '{}'", s)),
+        }
+    }
+}
+
+/// Direction along an axis.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// Direction of an arrow `^>v<` only, for maps where letters mean something else.
+    pub fn from_arrow(c: char) -> Result<Self> {
+        match c {
+            '^' => Ok(Direction::North),
+            '>' => Ok(Direction::East),
+            'v' => Ok(Direction::South),
+            '<' => Ok(Direction::West),
+            _ => Err(anyhow!("invalid arrow: '{}'", c.escape_default())),
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Arrows `^>v<`, compass points `NESW` or `UDLR`.
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            _ => Err(anyhow!("invalid direction: '{}'", c.escape_default())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<char>() {
+            Ok(c) => c.try_into(),
+            Err(_) => Err(anyhow!("invalid direction: '{}'", s)),
+        }
+    }
+}
+
+/// Direction along an axis or a diagonal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Direction an eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Unit offset of the direction, as `(dx, dy)`.
+    pub fn offset(self) -> (i8, i8) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+/// Compass points such as `N` or `SW`, in any case.
+impl FromStr for Direction8 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "N" => Ok(Direction8::North),
+            "NE" => Ok(Direction8::NorthEast),
+            "E" => Ok(Direction8::East),
+            "SE" => Ok(Direction8::SouthEast),
+            "S" => Ok(Direction8::South),
+            "SW" => Ok(Direction8::SouthWest),
+            "W" => Ok(Direction8::West),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => Err(anyhow!("invalid direction: '{}'", s)),
+        }
+    }
+}
+
+#[test]
+fn points() -> Result<()> {
+    let a = Point2::new(1, -2);
+    let b: Point2<i32> = "4, 2".parse()?;
+    assert_eq!(a + b, Point2::new(5, 0));
+    assert_eq!(b - a, Point2::new(3, 4));
+    assert_eq!(-a * 2, Point2::new(-2, 4));
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(Point2::new(2u32, 5).manhattan(Point2::new(4, 1)), 6);
+    assert_eq!(Point3::new(1, 2, 3).to_string(), "1,2,3");
+    assert_eq!("1,2,3,4".parse::<Point4<i64>>()?, Point4::new(1, 2, 3, 4));
+    assert!("1,2".parse::<Point3<i64>>().is_err());
+    assert!("1,2,3".parse::<Point2<i64>>().is_err());
+    assert!("1,x".parse::<Point2<i64>>().is_err());
+    Ok(())
+}
+
+#[test]
+fn neighbours() {
+    assert_eq!(Point2::new(0, 0).neighbours().len(), 8);
+    assert_eq!(Point3::new(0, 0, 0).neighbours().len(), 26);
+    assert_eq!(Point4::new(0, 0, 0, 0).neighbours().len(), 80);
+    assert!(Point3::new(0, 0, 0).neighbours().iter().all(|point| point.chebyshev(Point3::default()) == 1));
+    let neighbours: Vec<_> = Point2::new(0, 0).neighbours4().collect();
+    assert_eq!(neighbours, [Point2::new(0, -1), Point2::new(1, 0), Point2::new(0, 1), Point2::new(-1, 0)]);
+
+    // Unsigned points have no neighbours below zero.
+    let origin = Point2::<u32>::new(0, 0);
+    assert_eq!(origin.neighbours4().collect::<Vec<_>>(), [Point2::new(1, 0), Point2::new(0, 1)]);
+    assert_eq!(origin.neighbours(), [Point2::new(0, 1), Point2::new(1, 0), Point2::new(1, 1)]);
+    assert_eq!(Point3::<usize>::new(0, 0, 0).neighbours().len(), 7);
+    assert_eq!(origin.checked_step(Direction::West), None);
+    assert_eq!(origin.checked_walk(Direction8::SouthEast, 3), Some(Point2::new(3, 3)));
+    assert_eq!(Point2::new(u8::MAX, 0).checked_step(Direction::East), None);
+}
+
+#[test]
+fn directions() -> Result<()> {
+    assert_eq!(Direction::North.turn(Turn::Left), Direction::West);
+    assert_eq!(Direction::West.turn_right(), Direction::North);
+    assert_eq!(Direction::East.reverse(), Direction::West);
+    assert_eq!("^>v<".chars().map(Direction::try_from).collect::<Result<Vec<_>>>()?, Direction::ALL);
+    assert_eq!("NESW".chars().map(Direction::try_from).collect::<Result<Vec<_>>>()?, Direction::ALL);
+    assert_eq!("URDL".chars().map(Direction::try_from).collect::<Result<Vec<_>>>()?, Direction::ALL);
+    assert!(Direction::try_from('x').is_err());
+    assert_eq!("^>v<".chars().map(Direction::from_arrow).collect::<Result<Vec<_>>>()?, Direction::ALL);
+    assert!(Direction::from_arrow('N').is_err());
+    assert_eq!("R".parse::<Turn>()?, Turn::Right);
+    assert!("RL".parse::<Turn>().is_err());
+    assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+    assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+    assert_eq!(Direction8::from(Direction::South), Direction8::South);
+    assert_eq!("sw".parse::<Direction8>()?.reverse(), Direction8::NorthEast);
+    assert_eq!(Point2::new(0, 0).step(Direction::North), Point2::new(0, -1));
+    assert_eq!(Point2::new(0, 0).walk(Direction8::SouthEast, 3), Point2::new(3, 3));
+    Ok(())
+}
@@ -0,0 +1,371 @@
+//! Two-dimensional grid, such as the character maps of many puzzles.
+//!
+//! Cells are addressed by `(x, y)`, `x` being the column and `y` the row, from the top left corner.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Error, Result};
+
+/// Grid of cells stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid filled with a value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid whose cells are computed from their coordinates.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+        Self { width, height, cells }
+    }
+
+    /// Grid from its rows, which must have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(anyhow!("row {} has {} cells instead of {}", y + 1, rows[y].len(), width));
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Ok(Self { width, height, cells })
+    }
+
+    /// Parse a map of characters, one row per line.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let rows = input.lines().map(|line| line.chars().map(&mut f).collect()).collect::<Result<_>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.contains(x, y).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Replace a cell, returning its previous value, or `None` when it is out of the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Option<T> {
+        self.get_mut(x, y).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Cells with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(idx, cell)| ((idx % width, idx / width), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(idx, cell)| ((idx % width, idx / width), cell))
+    }
+
+    /// Cells, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Coordinates of the first cell matching a predicate, row by row.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells.iter().position(predicate).map(|idx| (idx % self.width, idx / self.width))
+    }
+
+    /// Grid with every cell mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Up, left, right and down neighbours within the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// Neighbours within the grid, diagonals included, row by row.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)])
+    }
+
+    fn neighbours(&self, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// View of a rectangle of the grid, `None` when it does not fit.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<View<'_, T>> {
+        self.fits(x, y, width, height).then_some(View { grid: self, x, y, width, height })
+    }
+
+    /// Mutable view of a rectangle of the grid, `None` when it does not fit.
+    pub fn view_mut(&mut self, x: usize, y: usize, width: usize, height: usize) -> Option<ViewMut<'_, T>> {
+        self.fits(x, y, width, height).then_some(ViewMut { grid: self, x, y, width, height })
+    }
+
+    fn fits(&self, x: usize, y: usize, width: usize, height: usize) -> bool {
+        x.checked_add(width).is_some_and(|end| end <= self.width) && y.checked_add(height).is_some_and(|end| end <= self.height)
+    }
+
+    /// Grid mirrored along its main diagonal, swapping rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Grid rotated by a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, self.height - 1 - x)].clone())
+    }
+
+    /// Grid rotated by a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |x, y| self[(self.width - 1 - y, x)].clone())
+    }
+
+    /// Grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.width, self.height, |x, y| self[(self.width - 1 - x, y)].clone())
+    }
+
+    /// Grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.width, self.height, |x, y| self[(x, self.height - 1 - y)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y), "({}, {}) out of a {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(self.contains(x, y), "({}, {}) out of a {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Ok)
+    }
+}
+
+/// Cells written row by row, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rectangle of a grid, addressed from its top left corner.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        (x < self.width && y < self.height).then(|| &self.grid[(self.x + x, self.y + y)])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (x, width) = (self.x, self.width);
+        self.grid.rows().skip(self.y).take(self.height).map(move |row| &row[x..x + width])
+    }
+
+    /// Cells, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// Copy of the rectangle.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| self.grid[(self.x + x, self.y + y)].clone())
+    }
+}
+
+/// Mutable rectangle of a grid, addressed from its top left corner.
+#[derive(Debug)]
+pub struct ViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> ViewMut<'_, T> {
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.grid[(self.x + x, self.y + y)])
+    }
+
+    /// Cells, row by row.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (x, width) = (self.x, self.width);
+        self.grid
+            .cells
+            .chunks_mut(self.grid.width.max(1))
+            .skip(self.y)
+            .take(self.height)
+            .flat_map(move |row| &mut row[x..x + width])
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.values_mut().for_each(|cell| *cell = value.clone());
+    }
+}
+
+#[test]
+fn parse() -> Result<()> {
+    let grid: Grid<char> = "#..\n.#.\n".parse()?;
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(1, 1), Some(&'#'));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.to_string(), "#..\n.#.\n");
+    assert!("#..\n.#\n".parse::<Grid<char>>().is_err());
+    let digits = Grid::parse("12\n34", |c| c.to_digit(10).ok_or_else(|| anyhow!("invalid digit: '{}'", c)))?;
+    assert_eq!(digits.values().sum::<u32>(), 10);
+    assert_eq!(digits.to_string(), "12\n34\n");
+    assert!(Grid::parse("1a", |c| c.to_digit(10).ok_or_else(|| anyhow!("invalid digit: '{}'", c))).is_err());
+    Ok(())
+}
+
+#[test]
+fn access() {
+    let mut grid = Grid::new(3, 2, 0);
+    assert_eq!(grid.set(2, 1, 5), Some(0));
+    assert_eq!(grid.set(3, 1, 5), None);
+    grid[(0, 1)] = 4;
+    assert_eq!(grid.row(1), Some(&[4, 0, 5][..]));
+    assert_eq!(grid.column(2).map(|column| column.copied().collect::<Vec<_>>()), Some(vec![0, 5]));
+    assert!(grid.column(3).is_none());
+    assert_eq!(grid.position(|&cell| cell == 5), Some((2, 1)));
+    assert_eq!(grid.iter().filter(|(_, &cell)| cell > 0).map(|(position, _)| position).collect::<Vec<_>>(), [(0, 1), (2, 1)]);
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::new(3, 3, ());
+    assert_eq!(grid.neighbours4(1, 1).collect::<Vec<_>>(), [(1, 0), (0, 1), (2, 1), (1, 2)]);
+    assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), [(1, 1), (2, 1), (1, 2)]);
+}
+
+#[test]
+fn views() -> Result<()> {
+    let mut grid: Grid<char> = "abc\ndef\nghi\n".parse()?;
+    let view = grid.view(1, 1, 2, 2).unwrap();
+    assert_eq!(view.get(0, 0), Some(&'e'));
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(view.values().collect::<String>(), "efhi");
+    assert_eq!(view.to_grid().to_string(), "ef\nhi\n");
+    assert!(grid.view(2, 0, 2, 1).is_none());
+    grid.view_mut(0, 1, 2, 2).unwrap().fill('.');
+    assert_eq!(grid.to_string(), "abc\n..f\n..i\n");
+    Ok(())
+}
+
+#[test]
+fn transforms() -> Result<()> {
+    let grid: Grid<char> = "abc\ndef\n".parse()?;
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Hexagonal grids, in axial coordinates.
+//!
+//! A hex is addressed by `q` and `r`, its cube coordinates being `(q, r, -q - r)`.
+//! The six neighbours are the same whatever the orientation of the hexes,
+//! which only changes how their directions are named: see [`FlatDirection`] and [`PointyDirection`].
+
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Error, Result};
+
+/// Hex in axial coordinates.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    /// Offsets of the six neighbours.
+    const OFFSETS: [Hex; 6] = [Hex::new(1, 0), Hex::new(1, -1), Hex::new(0, -1), Hex::new(-1, 0), Hex::new(-1, 1), Hex::new(0, 1)];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// Hex from cube coordinates, which must sum to zero.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Option<Self> {
+        (q + r + s == 0).then_some(Self { q, r })
+    }
+
+    /// Cube coordinates, as `(q, r, s)`.
+    pub fn cube(self) -> (i32, i32, i32) {
+        (self.q, self.r, -self.q - self.r)
+    }
+
+    /// Number of steps to another hex.
+    pub fn distance(self, other: Self) -> u32 {
+        let (q, r, s) = (self - other).cube();
+        (q.unsigned_abs() + r.unsigned_abs() + s.unsigned_abs()) / 2
+    }
+
+    /// Adjacent hex in a direction.
+    pub fn step(self, direction: impl Into<Hex>) -> Self {
+        self + direction.into()
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Self::OFFSETS.into_iter().map(move |offset| self + offset)
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+macro_rules! directions {
+    ($(#[$doc:meta])* $direction:ident { $($variant:ident = $name:literal ($q:literal, $r:literal)),+ $(,)? }) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum $direction {
+            $($variant,)+
+        }
+
+        impl $direction {
+            /// Every direction, clockwise.
+            pub const ALL: [$direction; 6] = [$($direction::$variant),+];
+
+            pub fn turn_left(self) -> Self {
+                Self::ALL[(self as usize + 5) % 6]
+            }
+
+            pub fn turn_right(self) -> Self {
+                Self::ALL[(self as usize + 1) % 6]
+            }
+
+            pub fn reverse(self) -> Self {
+                Self::ALL[(self as usize + 3) % 6]
+            }
+
+            /// Parse directions written without separators, such as `nwwswee`.
+            pub fn parse_path(s: &str) -> Result<Vec<Self>> {
+                let mut path = Vec::new();
+                let mut rest = s;
+                while !rest.is_empty() {
+                    // Two-letter names first, as `se` starts like `s` does.
+                    let (direction, len) = rest.get(..2).and_then(|name| name.parse().ok().map(|direction| (direction, 2)))
+                        .or_else(|| rest.get(..1).and_then(|name| name.parse().ok().map(|direction| (direction, 1))))
+                        .ok_or_else(|| anyhow!("invalid direction at '{}' in '{}'", rest, s))?;
+                    path.push(direction);
+                    rest = &rest[len..];
+                }
+                Ok(path)
+            }
+        }
+
+        impl From<$direction> for Hex {
+            fn from(direction: $direction) -> Self {
+                match direction {
+                    $($direction::$variant => Hex::new($q, $r),)+
+                }
+            }
+        }
+
+        /// Lowercase names such as `ne`.
+        impl FromStr for $direction {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($name => Ok($direction::$variant),)+
+                    _ => Err(anyhow!("invalid direction: '{}'", s)),
+                }
+            }
+        }
+    };
+}
+
+directions!(
+    /// Direction between flat-topped hexes, stacked in columns.
+    FlatDirection {
+        North = "n" (0, -1),
+        NorthEast = "ne" (1, -1),
+        SouthEast = "se" (1, 0),
+        South = "s" (0, 1),
+        SouthWest = "sw" (-1, 1),
+        NorthWest = "nw" (-1, 0),
+    }
+);
+
+directions!(
+    /// Direction between pointy-topped hexes, laid out in rows.
+    PointyDirection {
+        East = "e" (1, 0),
+        SouthEast = "se" (0, 1),
+        SouthWest = "sw" (-1, 1),
+        West = "w" (-1, 0),
+        NorthWest = "nw" (0, -1),
+        NorthEast = "ne" (1, -1),
+    }
+);
+
+#[test]
+fn coordinates() {
+    let hex = Hex::new(2, -3);
+    assert_eq!(hex.cube(), (2, -3, 1));
+    assert_eq!(Hex::from_cube(2, -3, 1), Some(hex));
+    assert_eq!(Hex::from_cube(2, -3, 0), None);
+    assert_eq!(hex.distance(Hex::default()), 3);
+    assert_eq!(Hex::default().distance(hex), 3);
+    assert_eq!(hex.neighbours().filter(|neighbour| neighbour.distance(hex) == 1).count(), 6);
+}
+
+#[test]
+fn directions() -> Result<()> {
+    for direction in FlatDirection::ALL {
+        assert_eq!(Hex::default().step(direction).step(direction.reverse()), Hex::default());
+        assert_eq!(direction.turn_right().turn_left(), direction);
+    }
+    for direction in PointyDirection::ALL {
+        assert_eq!(Hex::default().step(direction).distance(Hex::default()), 1);
+    }
+    let path = FlatDirection::parse_path("nenes")?;
+    assert_eq!(path, [FlatDirection::NorthEast, FlatDirection::NorthEast, FlatDirection::South]);
+    assert_eq!(path.into_iter().fold(Hex::default(), Hex::step).distance(Hex::default()), 2);
+    assert_eq!("sw".parse::<PointyDirection>()?, PointyDirection::SouthWest);
+    assert!("n".parse::<PointyDirection>().is_err());
+    let tile = PointyDirection::parse_path("nwwswee")?.into_iter().fold(Hex::default(), Hex::step);
+    assert_eq!(tile, Hex::default());
+    assert!(PointyDirection::parse_path("esx").is_err());
+    Ok(())
+}
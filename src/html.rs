@@ -0,0 +1,422 @@
+//! Minimal HTML helpers for the puzzle pages of the website.
+
+/// Decode the character references of an HTML text.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('&') {
+        decoded.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let entity = rest.find(';').filter(|end| *end <= 10).and_then(|end| decode_entity(&rest[1..end]).map(|char| (char, end)));
+        match entity {
+            Some((char, end)) => {
+                decoded.push(char);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => entity.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Remove the tags of an HTML fragment, keeping its text.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for char in html.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Puzzle title of a puzzle page, such as `Day 1: Not Quite Lisp`.
+///
+/// The title is the first `<h2>` of the `day-desc` article, written `--- Day 1: Not Quite Lisp ---`.
+pub fn puzzle_title(page: &str) -> Option<String> {
+    let article = &page[page.find("<article class=\"day-desc\">")?..];
+    let start = article.find("<h2")?;
+    let start = start + article[start..].find('>')? + 1;
+    let end = start + article[start..].find("</h2>")?;
+    let title = decode_entities(&strip_tags(&article[start..end]));
+    let title = title.trim().trim_start_matches('-').trim_end_matches('-').trim();
+    if title.is_empty() {
+        None
+    } else {
+        Some(title.to_owned())
+    }
+}
+
+/// Markdown rendering of the `day-desc` articles of a puzzle page, one per unlocked part.
+///
+/// Relative links are resolved against `url`, the URL of the page.
+pub fn puzzle_markdown(page: &str, url: &str) -> String {
+    let mut markdown = Markdown::new(url);
+    let mut rest = page;
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        for token in tokens(&article[..end]) {
+            markdown.push(token);
+        }
+        markdown.flush();
+        rest = &article[end..];
+    }
+    markdown.finish()
+}
+
+/// Contents of the `<pre><code>` blocks of the `day-desc` articles of a puzzle page, in order.
+pub fn puzzle_examples(page: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        let mut block = &article[..end];
+        while let Some(start) = block.find("<pre><code>") {
+            let code = &block[start + "<pre><code>".len()..];
+            let end = code.find("</code></pre>").unwrap_or(code.len());
+            let mut example = decode_entities(&strip_tags(&code[..end]));
+            if !example.ends_with('\n') {
+                example.push('\n');
+            }
+            examples.push(example);
+            block = &code[end..];
+        }
+        rest = &article[end..];
+    }
+    examples
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if let Some(end) = rest.strip_prefix('<').and_then(|_| rest.find('>')) {
+            let tag = rest[1..end].trim_end_matches('/');
+            match tag.strip_prefix('/') {
+                Some(name) => tokens.push(Token::Close(name.trim())),
+                None => {
+                    let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                    tokens.push(Token::Open(name, attributes));
+                }
+            }
+            rest = &rest[end + 1..];
+        } else {
+            // An unterminated `<` is text, up to the next one.
+            let end = rest.char_indices().skip(1).find(|&(_, c)| c == '<').map_or(rest.len(), |(end, _)| end);
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + attributes[start..].find('"')?;
+    Some(&attributes[start..end])
+}
+
+/// Markdown writer fed with HTML tokens.
+struct Markdown<'a> {
+    url: &'a str,
+    output: String,
+    inline: String,
+    heading: bool,
+    pre: Option<String>,
+    code: bool,
+    lists: Vec<Option<usize>>,
+    item: Option<String>,
+    links: Vec<String>,
+}
+
+impl<'a> Markdown<'a> {
+    fn new(url: &'a str) -> Self {
+        Self {
+            url,
+            output: String::new(),
+            inline: String::new(),
+            heading: false,
+            pre: None,
+            code: false,
+            lists: Vec::new(),
+            item: None,
+            links: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, token: Token<'_>) {
+        if let Some(pre) = &mut self.pre {
+            match token {
+                Token::Text(text) => pre.push_str(&decode_entities(text)),
+                Token::Close("pre") => {
+                    let code = self.pre.take().unwrap_or_default();
+                    let code = code.trim_start_matches('\n');
+                    self.output.push_str("```\n");
+                    self.output.push_str(code);
+                    if !code.ends_with('\n') {
+                        self.output.push('\n');
+                    }
+                    self.output.push_str("```\n\n");
+                }
+                _ => {}
+            }
+            return;
+        }
+        match token {
+            Token::Open("h2", _) => {
+                self.flush();
+                self.heading = true;
+            }
+            Token::Close("h2") => {
+                let heading = std::mem::take(&mut self.inline);
+                let heading = heading.trim().trim_start_matches('-').trim_end_matches('-').trim();
+                self.output.push_str(&format!("## {}\n\n", heading));
+                self.heading = false;
+            }
+            Token::Open("p", _) | Token::Close("p") => self.flush(),
+            Token::Open("pre", _) => {
+                self.flush();
+                self.pre = Some(String::new());
+            }
+            Token::Open("ul", _) | Token::Open("ol", _) => {
+                self.flush();
+                self.lists.push(if matches!(token, Token::Open("ol", _)) { Some(0) } else { None });
+            }
+            Token::Close("ul") | Token::Close("ol") => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.output.push('\n');
+                }
+            }
+            Token::Open("li", _) => {
+                self.flush();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(count)) => {
+                        *count += 1;
+                        format!("{}.", count)
+                    }
+                    _ => "-".to_owned(),
+                };
+                self.item = Some(format!("{}{} ", "  ".repeat(depth), marker));
+            }
+            Token::Close("li") => self.flush(),
+            Token::Open("code", _) => {
+                self.code = true;
+                self.inline.push('`');
+            }
+            Token::Close("code") => {
+                self.code = false;
+                self.inline.push('`');
+            }
+            Token::Open("em", _) | Token::Close("em") if !self.code => self.inline.push('*'),
+            Token::Open("a", attributes) => {
+                let href = attribute(attributes, "href").map(|href| self.resolve(href)).unwrap_or_default();
+                self.links.push(href);
+                self.inline.push('[');
+            }
+            Token::Close("a") => {
+                let href = self.links.pop().unwrap_or_default();
+                self.inline.push_str(&format!("]({})", href));
+            }
+            Token::Open("br", _) => self.inline.push_str("  \n"),
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                if self.code {
+                    self.inline.push_str(&collapse(&text));
+                } else {
+                    self.inline.push_str(&escape(&collapse(&text)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Write the pending paragraph or list item.
+    fn flush(&mut self) {
+        if self.heading {
+            return;
+        }
+        let text = std::mem::take(&mut self.inline);
+        let text = text.trim();
+        match self.item.take() {
+            Some(marker) => {
+                self.output.push_str(&marker);
+                self.output.push_str(text);
+                self.output.push('\n');
+            }
+            None if !text.is_empty() => {
+                self.output.push_str(text);
+                self.output.push_str("\n\n");
+            }
+            None => {}
+        }
+    }
+
+    fn resolve(&self, href: &str) -> String {
+        if href.starts_with("http://") || href.starts_with("https://") {
+            href.to_owned()
+        } else if let Some(path) = href.strip_prefix('/') {
+            let origin = self.url.find("://").and_then(|scheme| self.url[scheme + 3..].find('/').map(|end| &self.url[..scheme + 3 + end]));
+            format!("{}/{}", origin.unwrap_or(self.url), path)
+        } else {
+            let dir = self.url.rfind('/').map_or(self.url, |end| &self.url[..end]);
+            format!("{}/{}", dir, href)
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        let output = self.output.trim_end();
+        format!("{}\n", output)
+    }
+}
+
+/// Collapse the whitespace runs of an HTML text into single spaces.
+fn collapse(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = false;
+    for char in text.chars() {
+        if char.is_whitespace() {
+            if !space {
+                collapsed.push(' ');
+            }
+            space = true;
+        } else {
+            collapsed.push(char);
+            space = false;
+        }
+    }
+    collapsed
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        if matches!(char, '\\' | '*' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+    escaped
+}
+
+#[test]
+fn examples() {
+    let page = "\
+<pre><code>outside</code></pre>
+<article class=\"day-desc\"><h2>--- Day 7: Some Assembly Required ---</h2>
+<pre><code>123 -&gt; x
+<em>NOT</em> x -&gt; h
+</code></pre>
+<pre><code>+1, -2</code></pre>
+</article>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><pre><code>x\n</code></pre></article>";
+    assert_eq!(puzzle_examples(page), ["123 -> x\nNOT x -> h\n", "+1, -2\n", "x\n"]);
+}
+
+#[test]
+fn markdown() {
+    let page = "\
+<main>
+<article class=\"day-desc\"><h2>--- Day 1: Not Quite Lisp ---</h2><p>Santa is on floor <code>0</code>, see <a href=\"/2015/about\">about</a>.</p>
+<p>For example:</p>
+<ul>
+<li><code>(())</code> and <code>()()</code> both result in floor <code>0</code>.</li>
+<li>Nested:<ul><li><em>deep</em> item</li></ul></li>
+</ul>
+<pre><code>x AND y -&gt; d
+<em>NOT</em> x -&gt; h
+</code></pre>
+<p>To <em>what floor</em> do the instructions take Santa? 2*3</p>
+</article>
+<p>Your puzzle answer was <code>138</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Now, find the <a href=\"1/input\">position</a>.</p></article>
+</main>";
+    let expected = "\
+## Day 1: Not Quite Lisp
+
+Santa is on floor `0`, see [about](https://adventofcode.com/2015/about).
+
+For example:
+
+- `(())` and `()()` both result in floor `0`.
+- Nested:
+  - *deep* item
+
+```
+x AND y -> d
+NOT x -> h
+```
+
+To *what floor* do the instructions take Santa? 2\\*3
+
+## Part Two
+
+Now, find the [position](https://adventofcode.com/2015/day/1/input).
+";
+    assert_eq!(puzzle_markdown(page, "https://adventofcode.com/2015/day/1"), expected);
+}
+
+#[test]
+fn entities() {
+    assert_eq!(decode_entities("Doesn&apos;t He Have Intern-Elves For This?"), "Doesn't He Have Intern-Elves For This?");
+    assert_eq!(decode_entities("&lt;a&gt; &amp;&amp; &quot;b&quot; &#39;c&#x27;"), "<a> && \"b\" 'c'");
+    assert_eq!(decode_entities("AT&T &unknown; &"), "AT&T &unknown; &");
+}
+
+#[test]
+fn title() {
+    let page = "<p>--- not a title ---</p><article class=\"day-desc\"><h2>--- Day 5: Doesn&apos;t He Have Intern-Elves For This? ---</h2><p>---</p></article>";
+    assert_eq!(puzzle_title(page).as_deref(), Some("Day 5: Doesn't He Have Intern-Elves For This?"));
+    let page = "<article class=\"day-desc\"><h2 id=\"part2\">--- Day 13: <em>Care</em> Package ---</h2></article>";
+    assert_eq!(puzzle_title(page).as_deref(), Some("Day 13: Care Package"));
+    assert_eq!(puzzle_title("<h2>--- Day 4: Passport Processing ---</h2>"), None);
+    assert_eq!(puzzle_title("<article class=\"day-desc\"><h2>------</h2></article>"), None);
+}
+
+#[test]
+fn unterminated_tag() {
+    assert_eq!(tokens("a <"), [Token::Text("a "), Token::Text("<")]);
+    assert_eq!(tokens("<b>x < y"), [Token::Open("b", ""), Token::Text("x "), Token::Text("< y")]);
+    assert_eq!(tokens("<"), [Token::Text("<")]);
+    assert_eq!(puzzle_markdown("<article class=\"day-desc\"><p>1 < 2 <", "https://adventofcode.com/2015/day/1"), "1 < 2 <\n");
+}
@@ -0,0 +1,99 @@
+//! HTTP access to the Advent of Code website.
+
+use anyhow::{Context, Result};
+use isahc::{HttpClient, ReadResponseExt, Request};
+
+/// Base URL of the Advent of Code website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Base URL of the website: `AOC_BASE_URL` when set, [`BASE_URL`] otherwise.
+pub fn base_url() -> String {
+    std::env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_owned())
+}
+
+/// Status and body of an HTTP response.
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Backend fetching pages of the website, so that it can be replaced without network.
+pub trait Backend {
+    /// Base URL of the website, without a trailing slash.
+    fn base_url(&self) -> &str;
+
+    /// GET a path of the website, such as `/2015/day/1`.
+    fn get(&self, path: &str) -> Result<Response>;
+
+    /// POST an URL-encoded form to a path of the website.
+    fn post(&self, path: &str, form: &str) -> Result<Response>;
+
+    /// Full URL of a path of the website.
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url(), path)
+    }
+}
+
+/// Backend over `isahc`, authenticated with a session cookie.
+pub struct Client {
+    client: HttpClient,
+    base_url: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, cookie: &str) -> Result<Self> {
+        let cookie = format!("session={}", cookie);
+        let client = HttpClient::builder().default_header("Cookie", cookie).build()?;
+        let base_url = base_url.trim_end_matches('/').to_owned();
+        Ok(Self { client, base_url })
+    }
+}
+
+impl Backend for Client {
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn get(&self, path: &str) -> Result<Response> {
+        let url = self.url(path);
+        let mut response = self.client.get(&url).with_context(|| format!("GET {} failed", url))?;
+        let status = response.status().as_u16();
+        let body = response.text().with_context(|| format!("GET {}: invalid body", url))?;
+        Ok(Response { status, body })
+    }
+
+    fn post(&self, path: &str, form: &str) -> Result<Response> {
+        let url = self.url(path);
+        let request = Request::post(&url).header("Content-Type", "application/x-www-form-urlencoded").body(form.to_owned())?;
+        let mut response = self.client.send(request).with_context(|| format!("POST {} failed", url))?;
+        let status = response.status().as_u16();
+        let body = response.text().with_context(|| format!("POST {}: invalid body", url))?;
+        Ok(Response { status, body })
+    }
+}
+
+/// Encode a value of an URL-encoded form.
+pub fn form_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[test]
+fn form() {
+    assert_eq!(form_encode("1771"), "1771");
+    assert_eq!(form_encode("233,36 a&b=c"), "233%2C36+a%26b%3Dc");
+}
@@ -0,0 +1,111 @@
+//! Puzzle inputs, loaded at runtime so that they can be kept out of the repository.
+//!
+//! The input of a day is `<AOC_INPUTS>/<year>/<dd>.txt` when the `AOC_INPUTS` directory is set,
+//! `aoc_<year>/day_<dd>/input/input.txt` in the workspace otherwise.
+//! When only its encrypted copy, suffixed with `.enc`, exists, it is decrypted with the passphrase in `AOC_INPUT_KEY`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use chacha20poly1305::{aead::Aead, ChaCha20Poly1305, Key, KeyInit, Nonce};
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha256;
+
+/// Environment variable holding the inputs directory.
+pub const DIR_ENV: &str = "AOC_INPUTS";
+
+/// Environment variable holding the passphrase of the encrypted inputs.
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+
+/// Header of an encrypted input, followed by the salt, the nonce and the ciphertext.
+const MAGIC: &[u8] = b"aoc-input-v1\n";
+const SALT: usize = 16;
+const NONCE: usize = 12;
+const ITERATIONS: u32 = 100_000;
+
+/// Path of the input of a day, `root` being the workspace root, in the inputs directory of the environment if any.
+pub fn path(root: &Path, year: u32, day: u32) -> PathBuf {
+    let dir = std::env::var_os(DIR_ENV).filter(|dir| !dir.is_empty()).map(PathBuf::from);
+    path_in(root, dir.as_deref(), year, day)
+}
+
+/// Path of the input of a day, in an inputs directory or in the day crate under `root`.
+pub fn path_in(root: &Path, dir: Option<&Path>, year: u32, day: u32) -> PathBuf {
+    match dir {
+        Some(dir) => dir.join(format!("{}/{:02}.txt", year, day)),
+        None => root.join(format!("aoc_{}/day_{:02}/input/input.txt", year, day)),
+    }
+}
+
+/// Path of the encrypted copy of an input.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".enc");
+    path.into()
+}
+
+/// Load the input of a day with the directory and passphrase of the environment,
+/// `None` when it is missing or only encrypted without a passphrase set.
+pub fn load(root: &Path, year: u32, day: u32) -> Result<Option<String>> {
+    load_from(&path(root, year, day), std::env::var(KEY_ENV).ok().as_deref())
+}
+
+/// Load the input at a path, or its encrypted copy with a passphrase,
+/// `None` when it is missing or only encrypted without a passphrase.
+pub fn load_from(path: &Path, passphrase: Option<&str>) -> Result<Option<String>> {
+    if path.exists() {
+        return std::fs::read_to_string(path).map(Some).with_context(|| format!("cannot read input '{}'", path.display()));
+    }
+    let encrypted = encrypted_path(path);
+    let passphrase = match passphrase {
+        Some(passphrase) if encrypted.exists() => passphrase,
+        _ => return Ok(None),
+    };
+    let data = std::fs::read(&encrypted).with_context(|| format!("cannot read input '{}'", encrypted.display()))?;
+    decrypt(&data, passphrase).map(Some).with_context(|| format!("invalid encrypted input '{}'", encrypted.display()))
+}
+
+/// Encrypt an input with a passphrase.
+pub fn encrypt(input: &str, passphrase: &str) -> Result<Vec<u8>> {
+    let mut salt = [0; SALT];
+    let mut nonce = [0; NONCE];
+    getrandom::getrandom(&mut salt).map_err(|error| anyhow!("no random source: {}", error))?;
+    getrandom::getrandom(&mut nonce).map_err(|error| anyhow!("no random source: {}", error))?;
+    let ciphertext = cipher(passphrase, &salt)
+        .encrypt(Nonce::from_slice(&nonce), input.as_bytes())
+        .map_err(|_| anyhow!("encryption failed"))?;
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+/// Decrypt an input encrypted by [`encrypt`].
+pub fn decrypt(data: &[u8], passphrase: &str) -> Result<String> {
+    let data = data.strip_prefix(MAGIC).context("not an encrypted input")?;
+    if data.len() < SALT + NONCE {
+        return Err(anyhow!("truncated encrypted input"));
+    }
+    let (salt, data) = data.split_at(SALT);
+    let (nonce, ciphertext) = data.split_at(NONCE);
+    let input = cipher(passphrase, salt)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("cannot decrypt, wrong passphrase or corrupted input"))?;
+    String::from_utf8(input).context("decrypted input is not UTF-8")
+}
+
+fn cipher(passphrase: &str, salt: &[u8]) -> ChaCha20Poly1305 {
+    let mut key = [0; 32];
+    pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, ITERATIONS, &mut key);
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+#[test]
+fn encryption() -> Result<()> {
+    let input = "(()))(\n";
+    let encrypted = encrypt(input, "hunter2")?;
+    assert!(!encrypted.windows(input.len()).any(|window| window == input.as_bytes()));
+    assert_ne!(encrypt(input, "hunter2")?, encrypted);
+    assert_eq!(decrypt(&encrypted, "hunter2")?, input);
+    assert!(decrypt(&encrypted, "hunter3").is_err());
+    assert!(decrypt(&encrypted[..MAGIC.len() + SALT], "hunter2").is_err());
+    assert!(decrypt(input.as_bytes(), "hunter2").is_err());
+    Ok(())
+}
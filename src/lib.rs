@@ -1,6 +1,29 @@
-use std::io::{stdin, Read};
+pub mod answers;
+pub mod automaton;
+pub mod event;
+pub mod geom;
+pub mod grid;
+pub mod hex;
+pub mod html;
+pub mod http;
+pub mod input;
+pub mod ocr;
+pub mod search;
+pub mod session;
+pub mod setup;
+pub mod submit;
+pub mod template;
+pub mod tsp;
 
-use anyhow::{Context, Result};
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    io::{stdin, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context, Error, Result};
 
 /// Read input from standard input.
 pub fn input_from_stdin() -> Result<String> {
@@ -8,3 +31,139 @@ pub fn input_from_stdin() -> Result<String> {
     stdin().read_to_string(&mut input).context("stdin error")?;
     Ok(input)
 }
+
+/// Root of the workspace: the current directory or its first ancestor holding `answers.toml`.
+pub fn workspace_root() -> Result<PathBuf> {
+    let dir = std::env::current_dir()?;
+    dir.ancestors()
+        .find(|dir| dir.join(answers::FILE).exists())
+        .map(PathBuf::from)
+        .with_context(|| format!("{} not found from '{}'", answers::FILE, dir.display()))
+}
+
+/// Puzzle part.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Name of the part in `answers.toml`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Part::One => "part_one",
+            Part::Two => "part_two",
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part: '{}'", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+/// Solution to an Advent of Code puzzle.
+///
+/// The raw input is parsed once into [`Solution::Input`] and then shared by both parts.
+pub trait Solution {
+    /// Event year.
+    const YEAR: u32;
+    /// Puzzle day.
+    const DAY: u32;
+    /// Puzzle title, without the day prefix.
+    const TITLE: &'static str;
+
+    /// Parsed puzzle input.
+    type Input;
+    /// Answer to part one.
+    type PartOne: Display;
+    /// Answer to part two.
+    type PartTwo: Display;
+
+    /// Parse the raw puzzle input.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Solve part one.
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+
+    /// Solve part two.
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+/// Type-erased [`Solution`], so solutions of different days can be stored and driven together.
+pub trait DynSolution {
+    /// Event year.
+    fn year(&self) -> u32;
+
+    /// Puzzle day.
+    fn day(&self) -> u32;
+
+    /// Puzzle title, without the day prefix.
+    fn title(&self) -> &'static str;
+
+    /// Parse the raw puzzle input.
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Solve part one from an input returned by [`DynSolution::parse_input`].
+    fn solve_part_one(&self, input: &dyn Any) -> Result<String>;
+
+    /// Solve part two from an input returned by [`DynSolution::parse_input`].
+    fn solve_part_two(&self, input: &dyn Any) -> Result<String>;
+
+    /// Solve either part from an input returned by [`DynSolution::parse_input`].
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String> {
+        match part {
+            Part::One => self.solve_part_one(input),
+            Part::Two => self.solve_part_two(input),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_part_one(&self, input: &dyn Any) -> Result<String> {
+        let input = input.downcast_ref().ok_or_else(|| anyhow!("input type mismatch for {}-{:02}", S::YEAR, S::DAY))?;
+        Ok(S::part_one(input)?.to_string())
+    }
+
+    fn solve_part_two(&self, input: &dyn Any) -> Result<String> {
+        let input = input.downcast_ref().ok_or_else(|| anyhow!("input type mismatch for {}-{:02}", S::YEAR, S::DAY))?;
+        Ok(S::part_two(input)?.to_string())
+    }
+}
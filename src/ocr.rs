@@ -0,0 +1,95 @@
+//! Reading the capital letters that some puzzles draw instead of giving a number.
+//!
+//! Letters are 6 pixels high and 4 wide, `Y` excepted, on a 5 pixel pitch. Lit pixels are `#`, anything else is blank.
+
+use anyhow::{anyhow, Result};
+
+/// Height of a letter, in pixels.
+const HEIGHT: usize = 6;
+
+/// Width of a letter with the blank column after it.
+const PITCH: usize = 5;
+
+/// Letters of the font, with their rows.
+const LETTERS: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##..", "#..#.", "#..#.", "####.", "#..#.", "#..#."]),
+    ('B', ["###..", "#..#.", "###..", "#..#.", "#..#.", "###.."]),
+    ('C', [".##..", "#..#.", "#....", "#....", "#..#.", ".##.."]),
+    ('E', ["####.", "#....", "###..", "#....", "#....", "####."]),
+    ('F', ["####.", "#....", "###..", "#....", "#....", "#...."]),
+    ('G', [".##..", "#..#.", "#....", "#.##.", "#..#.", ".###."]),
+    ('H', ["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]),
+    ('I', [".###.", "..#..", "..#..", "..#..", "..#..", ".###."]),
+    ('J', ["..##.", "...#.", "...#.", "...#.", "#..#.", ".##.."]),
+    ('K', ["#..#.", "#.#..", "##...", "#.#..", "#.#..", "#..#."]),
+    ('L', ["#....", "#....", "#....", "#....", "#....", "####."]),
+    ('O', [".##..", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('P', ["###..", "#..#.", "#..#.", "###..", "#....", "#...."]),
+    ('R', ["###..", "#..#.", "#..#.", "###..", "#.#..", "#..#."]),
+    ('S', [".###.", "#....", "#....", ".##..", "...#.", "###.."]),
+    ('U', ["#..#.", "#..#.", "#..#.", "#..#.", "#..#.", ".##.."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####.", "...#.", "..#..", ".#...", "#....", "####."]),
+];
+
+/// Read the letters of a picture of 6 lines, ignoring the blank columns around them.
+pub fn read(picture: &str) -> Result<String> {
+    let rows: Vec<Vec<bool>> = picture.lines().map(|line| line.chars().map(|c| c == '#').collect()).collect();
+    if rows.len() != HEIGHT {
+        return Err(anyhow!("picture of {} lines instead of {}:\n{}", rows.len(), HEIGHT, picture));
+    }
+    let lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let Some(first) = (0..width).find(|&x| lit(x)) else { return Ok(String::new()) };
+    let end = (0..width).rfind(|&x| lit(x)).map_or(first, |x| x + 1);
+
+    // Letters such as `I` start with a blank column, so the cells are not known to start at the first lit one:
+    // try every alignment that puts it in the first cell.
+    let mut unknown = None;
+    for offset in 0..PITCH as isize {
+        match cells(&rows, first as isize - offset, end) {
+            Ok(letters) => return Ok(letters),
+            Err(left) => unknown = unknown.or(Some(left)),
+        }
+    }
+    Err(anyhow!("unknown letter at column {}:\n{}", unknown.unwrap_or_default(), picture))
+}
+
+/// Letters of the cells from `left` to `end`, or the column of the first unknown one.
+fn cells(rows: &[Vec<bool>], left: isize, end: usize) -> Result<String, isize> {
+    let mut letters = String::new();
+    for left in (left..end as isize).step_by(PITCH) {
+        let pixel = |row: &Vec<bool>, x: isize| usize::try_from(left + x).ok().and_then(|x| row.get(x)).copied().unwrap_or(false);
+        let letter = LETTERS
+            .iter()
+            .find(|(_, pattern)| rows.iter().zip(pattern).all(|(row, pattern)| pattern.chars().zip(0..).all(|(c, x)| pixel(row, x) == (c == '#'))));
+        match letter {
+            Some(&(letter, _)) => letters.push(letter),
+            None => return Err(left),
+        }
+    }
+    Ok(letters)
+}
+
+#[test]
+fn letters() -> Result<()> {
+    let picture = [
+        "..#..#.####.#....#.....##...",
+        "..#..#.#....#....#....#..#..",
+        "..####.###..#....#....#..#..",
+        "..#..#.#....#....#....#..#..",
+        "..#..#.#....#....#....#..#..",
+        "..#..#.####.####.####..##...",
+    ];
+    assert_eq!(read(&picture.join("\n"))?, "HELLO");
+    assert_eq!(read(&["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."].join("\n"))?, "Y");
+    assert_eq!(read(&["...."; 6].join("\n"))?, "");
+
+    // The first letter starting with a blank column.
+    let picture = [".###.###..####.", "..#..#..#.#....", "..#..#..#.###..", "..#..###..#....", "..#..#.#..#....", ".###.#..#.####."];
+    assert_eq!(read(&picture.join("\n"))?, "IRE");
+    assert_eq!(read(&picture.map(|row| &row[1..]).join("\n"))?, "IRE");
+    assert!(read("#").is_err());
+    assert!(read(&["####"; 6].join("\n")).is_err());
+    Ok(())
+}
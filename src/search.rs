@@ -0,0 +1,350 @@
+//! Searches over graphs given by a successor function.
+//!
+//! States are any hashable values and costs any ordered numbers,
+//! the successors of a state being computed on demand so that graphs need not be built beforehand.
+//! Orders of a few items, such as routes whose cost is not a sum of edges, are searched by brute force with [`for_each_permutation`].
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use anyhow::Result;
+
+/// Cost of an edge, zero being the default value.
+pub trait Cost: Copy + Default + Ord + Add<Output = Self> {}
+
+impl<C: Copy + Default + Ord + Add<Output = C>> Cost for C {}
+
+/// Path from the start to a goal, both included, with its total cost.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+/// States seen by a search, each with its predecessor and its cost from the start.
+struct Visited<S, C> {
+    index: HashMap<S, usize>,
+    states: Vec<(S, usize, C)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            states: vec![(start, 0, cost)],
+        }
+    }
+
+    /// Record a state reached from another, unless already reached at a lower cost.
+    fn visit(&mut self, state: S, parent: usize, cost: C) -> Option<usize>
+    where
+        C: Ord,
+    {
+        match self.index.get(&state) {
+            Some(&i) if self.states[i].2 <= cost => None,
+            Some(&i) => {
+                self.states[i] = (state, parent, cost);
+                Some(i)
+            }
+            None => {
+                self.index.insert(state.clone(), self.states.len());
+                self.states.push((state, parent, cost));
+                Some(self.states.len() - 1)
+            }
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Path<S, C> {
+        let cost = self.states[i].2;
+        let mut states = vec![self.states[i].0.clone()];
+        while i != 0 {
+            i = self.states[i].1;
+            states.push(self.states[i].0.clone());
+        }
+        states.reverse();
+        Path { states, cost }
+    }
+
+    fn costs(self) -> HashMap<S, C> {
+        self.states.into_iter().map(|(state, _, cost)| (state, cost)).collect()
+    }
+}
+
+/// Shortest path to a goal, every move costing one, of at most `max_depth` moves or without limit.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut goal: impl FnMut(&S) -> bool, max_depth: Option<usize>) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let (state, _, depth) = &visited.states[i];
+        if goal(state) {
+            return Some(visited.path(i));
+        }
+        if max_depth.is_some_and(|max_depth| *depth >= max_depth) {
+            continue;
+        }
+        let depth = depth + 1;
+        for next in successors(state) {
+            queue.extend(visited.visit(next, i, depth));
+        }
+    }
+    None
+}
+
+/// Number of moves to every state reachable in at most `max_depth` moves, or without limit.
+pub fn distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I, max_depth: Option<usize>) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let (state, _, depth) = &visited.states[i];
+        if max_depth.is_some_and(|max_depth| *depth >= max_depth) {
+            continue;
+        }
+        let depth = depth + 1;
+        for next in successors(state) {
+            queue.extend(visited.visit(next, i, depth));
+        }
+    }
+    visited.costs()
+}
+
+/// Cheapest path to a goal, successors coming with the cost of the move, costing at most `max_cost` or without limit.
+///
+/// The bound is on the cost rather than on the number of moves, which only matches the cheapest paths when every move costs the same.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I, goal: impl FnMut(&S) -> bool, max_cost: Option<C>) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), goal, max_cost)
+}
+
+/// Cost of the cheapest path to every state reachable for at most `max_cost`, or without limit.
+pub fn costs<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, max_cost: Option<C>) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new(start, C::default());
+    let mut queue = BinaryHeap::from([Reverse((C::default(), 0))]);
+    while let Some(Reverse((cost, i))) = queue.pop() {
+        if cost > visited.states[i].2 {
+            continue;
+        }
+        for (next, step) in successors(&visited.states[i].0) {
+            let cost = cost + step;
+            if max_cost.is_some_and(|max_cost| cost > max_cost) {
+                continue;
+            }
+            queue.extend(visited.visit(next, i, cost).map(|next| Reverse((cost, next))));
+        }
+    }
+    visited.costs()
+}
+
+/// Cheapest path to a goal, guided by a heuristic that must never overestimate the remaining cost,
+/// costing at most `max_cost` or without limit.
+pub fn astar<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, mut heuristic: impl FnMut(&S) -> C, mut goal: impl FnMut(&S) -> bool, max_cost: Option<C>) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        let state = &visited.states[i].0;
+        if cost > visited.states[i].2 {
+            continue;
+        }
+        if goal(state) {
+            return Some(visited.path(i));
+        }
+        for (next, step) in successors(state) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+            if max_cost.is_some_and(|max_cost| estimate > max_cost) {
+                continue;
+            }
+            queue.extend(visited.visit(next, i, cost).map(|next| Reverse((estimate, cost, next))));
+        }
+    }
+    None
+}
+
+/// Cost of the cheapest path between every pair of nodes numbered from 0, given the directed edges between them.
+pub fn floyd_warshall<C: Cost>(nodes: usize, edges: impl IntoIterator<Item = (usize, usize, C)>) -> Vec<Vec<Option<C>>> {
+    let mut costs = vec![vec![None; nodes]; nodes];
+    for (node, row) in costs.iter_mut().enumerate() {
+        row[node] = Some(C::default());
+    }
+    for (from, to, cost) in edges {
+        if costs[from][to].is_none_or(|current| cost < current) {
+            costs[from][to] = Some(cost);
+        }
+    }
+    for via in 0..nodes {
+        let through = costs[via].clone();
+        for row in &mut costs {
+            let Some(first) = row[via] else { continue };
+            for (current, second) in row.iter_mut().zip(&through) {
+                if let Some(second) = second {
+                    if current.is_none_or(|current| first + *second < current) {
+                        *current = Some(first + *second);
+                    }
+                }
+            }
+        }
+    }
+    costs
+}
+
+/// Call `f` with every order of `items`, stopping at its first error.
+///
+/// Items are permuted in place by Heap's algorithm, each order differing from the previous one by a swap.
+pub fn for_each_permutation<T>(items: &mut [T], mut f: impl FnMut(&[T]) -> Result<()>) -> Result<()> {
+    fn heap<T>(items: &mut [T], len: usize, f: &mut impl FnMut(&[T]) -> Result<()>) -> Result<()> {
+        if len <= 1 {
+            return f(items);
+        }
+        for i in 0..len - 1 {
+            heap(items, len - 1, f)?;
+            items.swap(if len.is_multiple_of(2) { i } else { 0 }, len - 1);
+        }
+        heap(items, len - 1, f)
+    }
+    let len = items.len();
+    heap(items, len, &mut f)
+}
+
+/// Directed graph of the tests.
+#[cfg(test)]
+const EDGES: [(char, char, u32); 9] = [
+    ('a', 'b', 7),
+    ('a', 'c', 9),
+    ('a', 'f', 14),
+    ('b', 'c', 10),
+    ('b', 'd', 15),
+    ('c', 'd', 11),
+    ('c', 'f', 2),
+    ('d', 'e', 6),
+    ('f', 'e', 9),
+];
+
+#[cfg(test)]
+fn successors(node: &char) -> Vec<(char, u32)> {
+    EDGES.iter().filter(|&&(from, _, _)| from == *node).map(|&(_, to, cost)| (to, cost)).collect()
+}
+
+#[test]
+fn unweighted() {
+    let moves = |node: &char| successors(node).into_iter().map(|(to, _)| to);
+    let path = bfs('a', moves, |&node| node == 'e', None);
+    assert_eq!(path, Some(Path { states: vec!['a', 'f', 'e'], cost: 2 }));
+    assert_eq!(bfs('a', moves, |&node| node == 'e', Some(2)).map(|path| path.cost), Some(2));
+    assert_eq!(bfs('a', moves, |&node| node == 'e', Some(1)), None);
+    assert_eq!(bfs('a', moves, |&node| node == 'a', Some(0)).map(|path| path.cost), Some(0));
+    assert_eq!(bfs('e', moves, |&node| node == 'a', None), None);
+    let within = distances('a', moves, Some(1));
+    assert_eq!(within.len(), 4);
+    assert_eq!(within.get(&'f'), Some(&1));
+    assert_eq!(distances('a', moves, None).get(&'e'), Some(&2));
+
+    // Grid of 10 by 10 cells, without the bottom right one.
+    let grid = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && (x, y) != (9, 9))
+    };
+    assert_eq!(bfs((0, 0), grid, |&cell| cell == (9, 8), None).map(|path| path.cost), Some(17));
+    assert_eq!(distances((0, 0), grid, None).len(), 99);
+}
+
+#[test]
+fn weighted() {
+    let path = dijkstra('a', successors, |&node| node == 'e', None);
+    assert_eq!(
+        path,
+        Some(Path {
+            states: vec!['a', 'c', 'f', 'e'],
+            cost: 20
+        })
+    );
+    assert_eq!(dijkstra('f', successors, |&node| node == 'a', None), None);
+    assert_eq!(dijkstra('a', successors, |&node| node == 'e', Some(20)).map(|path| path.cost), Some(20));
+    assert_eq!(dijkstra('a', successors, |&node| node == 'e', Some(19)), None);
+    let all = costs('a', successors, None);
+    assert_eq!(all.get(&'d'), Some(&20));
+    assert_eq!(all.get(&'f'), Some(&11));
+    let within = costs('a', successors, Some(11));
+    assert_eq!(within.len(), 4);
+    assert_eq!(within.get(&'d'), None);
+
+    // Manhattan distance never overestimates on a grid with unit moves and walls.
+    let wall = |(x, y): (i32, i32)| x == 5 && y < 8;
+    let grid = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+            .into_iter()
+            .filter(move |&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && !wall((x, y)))
+            .map(|cell| (cell, 1))
+    };
+    let heuristic = |&(x, y): &(i32, i32)| (9 - x).abs() + y.abs();
+    let path = astar((0, 0), grid, heuristic, |&cell| cell == (9, 0), None);
+    assert_eq!(path.as_ref().map(|path| path.cost), Some(25));
+    assert_eq!(path.map(|path| path.states.len()), Some(26));
+    assert_eq!(dijkstra((0, 0), grid, |&cell| cell == (9, 0), None).map(|path| path.cost), Some(25));
+    assert_eq!(astar((0, 0), grid, heuristic, |&cell| cell == (9, 0), Some(25)).map(|path| path.cost), Some(25));
+    assert_eq!(astar((0, 0), grid, heuristic, |&cell| cell == (9, 0), Some(24)), None);
+}
+
+#[test]
+fn all_pairs() {
+    let node = |c: char| c as usize - 'a' as usize;
+    let costs = floyd_warshall(7, EDGES.iter().map(|&(from, to, cost)| (node(from), node(to), cost)));
+    assert_eq!(costs[node('a')][node('e')], Some(20));
+    assert_eq!(costs[node('b')][node('e')], Some(21));
+    assert_eq!(costs[node('e')][node('a')], None);
+    assert_eq!(costs[node('g')][node('g')], Some(0));
+    assert_eq!(costs[node('a')][node('g')], None);
+}
+
+#[test]
+fn permutations() -> Result<()> {
+    let mut items = [1, 2, 3, 4];
+    let mut seen = std::collections::HashSet::new();
+    for_each_permutation(&mut items, |order| {
+        seen.insert(order.to_vec());
+        Ok(())
+    })?;
+    assert_eq!(seen.len(), 24);
+
+    let mut calls = 0;
+    let stopped = for_each_permutation(&mut items, |order| {
+        calls += 1;
+        match order[0] {
+            4 => Err(anyhow::anyhow!("stop")),
+            _ => Ok(()),
+        }
+    });
+    assert!(stopped.is_err() && calls < 24);
+    let mut calls = 0;
+    for_each_permutation::<u8>(&mut [], |_| {
+        calls += 1;
+        Ok(())
+    })?;
+    assert_eq!(calls, 1);
+    Ok(())
+}
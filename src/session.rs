@@ -0,0 +1,107 @@
+//! Session cookie of the website, kept out of the command line.
+//!
+//! The cookie is looked up, in order, in:
+//! - the `AOC_SESSION` environment variable,
+//! - a `.aoc-session` file in the current directory or one of its ancestors,
+//! - `aoc/session` in the user's config directory.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::http::Backend;
+
+/// Environment variable holding the cookie.
+pub const ENV: &str = "AOC_SESSION";
+
+/// Name of the cookie file looked up from the current directory.
+pub const FILE: &str = ".aoc-session";
+
+/// Session cookie and where it was found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub cookie: String,
+    pub source: Source,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Env,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Env => write!(f, "{}", ENV),
+            Source::File(path) => write!(f, "'{}'", path.display()),
+        }
+    }
+}
+
+impl Session {
+    /// Find the cookie from the environment, the current directory or the config directory.
+    pub fn find() -> Result<Self> {
+        Self::find_in(std::env::var(ENV).ok(), &std::env::current_dir()?, config_dir().as_deref())
+    }
+
+    /// Find the cookie from the given environment value, directory and config directory.
+    pub fn find_in(env: Option<String>, dir: &Path, config_dir: Option<&Path>) -> Result<Self> {
+        if let Some(cookie) = env {
+            return Self::new(&cookie, Source::Env);
+        }
+        let local = dir.ancestors().map(|dir| dir.join(FILE));
+        let config = config_dir.map(|dir| dir.join("aoc/session"));
+        for path in local.chain(config) {
+            if path.exists() {
+                let cookie = std::fs::read_to_string(&path).with_context(|| format!("cannot read session cookie '{}'", path.display()))?;
+                return Self::new(&cookie, Source::File(path));
+            }
+        }
+        let config = config_dir.map_or_else(|| "aoc/session in the config directory".to_owned(), |dir| format!("'{}'", dir.join("aoc/session").display()));
+        Err(anyhow!("no session cookie: set {}, or write it to a {} file or to {}", ENV, FILE, config))
+    }
+
+    /// Check the syntax of a cookie, accepting a leading `session=`.
+    fn new(cookie: &str, source: Source) -> Result<Self> {
+        let cookie = cookie.trim();
+        let cookie = cookie.strip_prefix("session=").unwrap_or(cookie);
+        if cookie.is_empty() {
+            return Err(anyhow!("empty session cookie in {}", source));
+        }
+        if !cookie.chars().all(|char| char.is_ascii_alphanumeric()) {
+            return Err(anyhow!("invalid session cookie in {}: expected the value of the 'session' cookie", source));
+        }
+        Ok(Self { cookie: cookie.to_owned(), source })
+    }
+
+    /// Check that the website accepts the cookie, with a request to the settings page.
+    pub fn validate(&self, backend: &dyn Backend) -> Result<()> {
+        let response = backend.get("/settings")?;
+        if !response.is_success() {
+            return Err(anyhow!(
+                "session cookie from {} was rejected with status {}, it may have expired: log in again and copy the new one",
+                self.source,
+                response.status
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Config directory of the user: `XDG_CONFIG_HOME`, `~/.config` or `%APPDATA%`.
+fn config_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+    var("XDG_CONFIG_HOME").or_else(|| var("HOME").map(|home| home.join(".config"))).or_else(|| var("APPDATA"))
+}
+
+#[test]
+fn syntax() -> Result<()> {
+    assert_eq!(Session::new(" session=53616c7465645f5f\n", Source::Env)?.cookie, "53616c7465645f5f");
+    assert!(Session::new("\n", Source::Env).is_err());
+    assert!(Session::new("abc; path=/", Source::Env).is_err());
+    Ok(())
+}
@@ -0,0 +1,283 @@
+//! Scaffolding of the day crates from the Advent of Code website.
+
+use std::{ffi::OsStr, fmt, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::{
+    html::{puzzle_examples, puzzle_markdown, puzzle_title},
+    http::Backend,
+    input,
+    template::{crate_name, Template},
+};
+
+/// How [`setup_layout`] handles a day crate which already exists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Leave it untouched.
+    Create,
+    /// Add its missing files, such as the input, the README or the tests, never overwriting one.
+    Update,
+}
+
+/// Create the crate of a day, unless the puzzle is not available yet.
+pub fn setup_layout(root: &Path, backend: &dyn Backend, template: &Template, year: u32, day: u32, mode: Mode) -> Result<()> {
+    let path = root.join(format!("aoc_{}/day_{:02}", year, day));
+    if path.exists() && mode == Mode::Create {
+        return Ok(());
+    }
+    let crate_name = crate_name(year, day);
+    let test_file = format!("tests/{}.rs", crate_name);
+    let missing = |file: &str| !path.join(file).exists();
+    let input_path = input::path(root, year, day);
+    let missing_input = !input_path.exists() && !input::encrypted_path(&input_path).exists();
+    if ["Cargo.toml", "src/lib.rs", &test_file, "README.md"].iter().any(|file| missing(file)) || missing_input {
+        let response = backend.get(&format!("/{}/day/{}", year, day))?;
+        if !response.is_success() {
+            return Ok(());
+        }
+        let title = puzzle_title(&response.body).with_context(|| format!("title of {}-{:02} not found", year, day))?;
+        let examples = puzzle_examples(&response.body);
+        dir(&path)?;
+        if missing("Cargo.toml") {
+            cargo(&path, template, year, day)?;
+        }
+        src_dir(&path)?;
+        if missing("src/lib.rs") {
+            lib(&path, template, year, day, &title, examples.len())?;
+        }
+        tests_dir(&path)?;
+        if missing(&test_file) {
+            test(&path, template, year, day)?;
+        }
+        if missing("README.md") {
+            readme(&path, backend, year, day, &response.body)?;
+        }
+        input_dir(&path)?;
+        if missing_input {
+            fetch_input(root, backend, year, day)?;
+        }
+        write_examples(&path, &examples)?;
+    }
+    workspace(root, year)?;
+    register(root, &crate_name, year, day)
+}
+
+/// Rewrite the README of every selected day crate from its puzzle page, leaving the solutions untouched.
+///
+/// Once part one is solved, the page also describes part two, which is then added to the README.
+pub fn refresh_readmes(root: &Path, backend: &dyn Backend, selected: impl Fn(u32, u32) -> bool) -> Result<()> {
+    for (year, day) in existing_days(root)?.into_iter().filter(|&(year, day)| selected(year, day)) {
+        let path = root.join(format!("aoc_{}/day_{:02}", year, day));
+        let response = backend.get(&format!("/{}/day/{}", year, day))?;
+        if !response.is_success() {
+            return Err(anyhow!("puzzle {}-{:02} not available: HTTP {}", year, day, response.status));
+        }
+        readme(&path, backend, year, day, &response.body)?;
+    }
+    Ok(())
+}
+
+/// Boilerplate file of a day crate which differs from the templates.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Drift {
+    pub year: u32,
+    pub day: u32,
+    /// Path of the file, relative to the day crate.
+    pub file: String,
+    pub missing: bool,
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.missing { "missing" } else { "differs from the template" };
+        write!(f, "aoc_{}/day_{:02}/{}: {}", self.year, self.day, self.file, state)
+    }
+}
+
+/// Compare the boilerplate of every day crate, its `Cargo.toml` and answer tests, with the templates.
+///
+/// Solutions are not compared, as they are meant to depart from the template.
+pub fn check_drift(root: &Path, template: &Template) -> Result<Vec<Drift>> {
+    let mut drifts = Vec::new();
+    for (year, day) in existing_days(root)? {
+        let path = root.join(format!("aoc_{}/day_{:02}", year, day));
+        // Dependencies added by a solution follow those of the template.
+        let files = [
+            ("Cargo.toml".to_owned(), template.manifest(year, day), true),
+            (format!("tests/{}.rs", crate_name(year, day)), template.test(year, day), false),
+        ];
+        for (file, expected, extensible) in files {
+            let contents = std::fs::read_to_string(path.join(&file)).ok();
+            if contents.as_ref().is_some_and(|contents| *contents == expected || (extensible && contents.starts_with(&expected))) {
+                continue;
+            }
+            drifts.push(Drift {
+                year,
+                day,
+                file,
+                missing: contents.is_none(),
+            });
+        }
+    }
+    Ok(drifts)
+}
+
+/// Year and day of every day crate under the workspace root, in order.
+fn existing_days(root: &Path) -> Result<Vec<(u32, u32)>> {
+    let mut days = Vec::new();
+    for entry in std::fs::read_dir(root)? {
+        let entry = entry?;
+        if let Some(year) = numbered(&entry.file_name(), "aoc_") {
+            for entry in std::fs::read_dir(entry.path())? {
+                if let Some(day) = numbered(&entry?.file_name(), "day_") {
+                    days.push((year, day));
+                }
+            }
+        }
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+fn numbered(name: &OsStr, prefix: &str) -> Option<u32> {
+    name.to_str()?.strip_prefix(prefix)?.parse().ok()
+}
+
+fn dir(path: &Path) -> Result<()> {
+    std::fs::create_dir_all(path)?;
+    Ok(())
+}
+
+fn cargo(path: &Path, template: &Template, year: u32, day: u32) -> Result<()> {
+    std::fs::write(path.join("Cargo.toml"), template.manifest(year, day))?;
+    Ok(())
+}
+
+fn src_dir(path: &Path) -> Result<()> {
+    let path = path.join("src");
+    std::fs::create_dir_all(path)?;
+    Ok(())
+}
+
+fn lib(path: &Path, template: &Template, year: u32, day: u32, title: &str, examples: usize) -> Result<()> {
+    std::fs::write(path.join("src/lib.rs"), template.lib(year, day, title, examples))?;
+    Ok(())
+}
+
+fn tests_dir(path: &Path) -> Result<()> {
+    let path = path.join("tests");
+    std::fs::create_dir_all(path)?;
+    Ok(())
+}
+
+fn test(path: &Path, template: &Template, year: u32, day: u32) -> Result<()> {
+    std::fs::write(path.join(format!("tests/{}.rs", crate_name(year, day))), template.test(year, day))?;
+    Ok(())
+}
+
+fn readme(path: &Path, backend: &dyn Backend, year: u32, day: u32, page: &str) -> Result<()> {
+    let path = path.join("README.md");
+    let url = backend.url(&format!("/{}/day/{}", year, day));
+    let title = puzzle_title(page).with_context(|| format!("title of {}-{:02} not found", year, day))?;
+    let contents = format!(
+        "\
+# Advent of Code -- Year {} - Day {:02}
+
+[{}]({})
+
+{}",
+        year,
+        day,
+        title,
+        url,
+        puzzle_markdown(page, &url)
+    );
+    std::fs::write(path, contents)?;
+    Ok(())
+}
+
+fn input_dir(path: &Path) -> Result<()> {
+    let path = path.join("input");
+    std::fs::create_dir_all(path)?;
+    Ok(())
+}
+
+fn write_examples(path: &Path, examples: &[String]) -> Result<()> {
+    for (idx, example) in examples.iter().enumerate() {
+        let path = path.join(format!("input/example_{}.txt", idx + 1));
+        if !path.exists() {
+            std::fs::write(path, example)?;
+        }
+    }
+    Ok(())
+}
+
+/// Add the crates of a year to the workspace members, unless a pattern already covers them.
+fn workspace(root: &Path, year: u32) -> Result<()> {
+    let path = root.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&path)?;
+    let member = format!("\"aoc_{}/day_*\"", year);
+    if manifest.contains("\"aoc_*/day_*\"") || manifest.contains(&member) {
+        return Ok(());
+    }
+    let members = manifest.replacen("members = [", &format!("members = [{}, ", member), 1);
+    if members == manifest {
+        return Err(anyhow!("no workspace members in '{}'", path.display()));
+    }
+    std::fs::write(&path, members)?;
+    Ok(())
+}
+
+fn register(root: &Path, crate_name: &str, year: u32, day: u32) -> Result<()> {
+    let path = root.join("runner/Cargo.toml");
+    let mut manifest = std::fs::read_to_string(&path)?;
+    if !manifest.contains(&format!("\n{} = ", crate_name)) {
+        manifest.push_str(&format!("{} = {{ path = \"../aoc_{}/day_{:02}\" }}\n", crate_name, year, day));
+        std::fs::write(&path, manifest)?;
+    }
+    let path = root.join("runner/src/registry.rs");
+    let registry = std::fs::read_to_string(&path)?;
+    let entry = format!("    &{}::Solver,\n", crate_name);
+    if !registry.contains(&entry) {
+        let registry = registry.replacen("];\n", &format!("{}];\n", entry), 1);
+        std::fs::write(&path, registry)?;
+    }
+    Ok(())
+}
+
+/// Download the input of a day to its path in the inputs directory, returning it.
+pub fn fetch_input(root: &Path, backend: &dyn Backend, year: u32, day: u32) -> Result<String> {
+    let path = input::path(root, year, day);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let response = backend.get(&format!("/{}/day/{}/input", year, day))?;
+    if !response.is_success() {
+        return Err(anyhow!("input of {}-{:02} not available: HTTP {}", year, day, response.status));
+    }
+    std::fs::write(&path, &response.body).with_context(|| format!("cannot write input '{}'", path.display()))?;
+    Ok(response.body)
+}
+
+/// Parse a list of numbers and inclusive ranges, such as `2015,2017-2019`.
+pub fn parse_numbers(s: &str) -> Result<Vec<u32>> {
+    let mut numbers = Vec::new();
+    for item in s.split(',') {
+        let number = |s: &str| s.trim().parse::<u32>().with_context(|| format!("invalid number: '{}'", s));
+        match item.split_once('-') {
+            Some((start, end)) => numbers.extend(number(start)?..=number(end)?),
+            None => numbers.push(number(item)?),
+        }
+    }
+    Ok(numbers)
+}
+
+#[test]
+fn numbers() -> Result<()> {
+    assert_eq!(parse_numbers("5")?, [5]);
+    assert_eq!(parse_numbers("2015,2017-2019")?, [2015, 2017, 2018, 2019]);
+    assert!(parse_numbers("1-").is_err());
+    assert!(parse_numbers("one").is_err());
+    Ok(())
+}
@@ -0,0 +1,363 @@
+//! Answer submission, with a local log of every attempt.
+//!
+//! The log is a tab separated file, one attempt per line:
+//! `<timestamp> <year> <day> <part> <outcome> <wait> <answer>`, `wait` being the seconds the website asked to wait.
+//! It is checked before posting so an answer is never submitted twice, nor while the website asks to wait.
+
+use std::{
+    fmt,
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context, Error, Result};
+
+use crate::{
+    answers::Answers,
+    html,
+    http::{self, Backend},
+    Part,
+};
+
+/// Name of the attempts log.
+pub const LOG: &str = "submissions.tsv";
+
+/// Verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Incorrect,
+    /// Not checked, an answer was submitted too recently.
+    TooRecent,
+    /// Not checked, the part is already solved or locked.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Name of the outcome in the log.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooRecent => "too_recent",
+            Outcome::WrongLevel => "wrong_level",
+        }
+    }
+
+    /// Whether the answer was checked, so submitting it again is pointless.
+    pub fn is_checked(&self) -> bool {
+        !matches!(self, Outcome::TooRecent | Outcome::WrongLevel)
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "too_recent" => Ok(Outcome::TooRecent),
+            "wrong_level" => Ok(Outcome::WrongLevel),
+            _ => Err(anyhow!("invalid outcome: '{}'", s)),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooRecent => write!(f, "answered too recently"),
+            Outcome::WrongLevel => write!(f, "already solved or locked"),
+        }
+    }
+}
+
+/// Response of the website to a submission.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Verdict {
+    pub outcome: Outcome,
+    /// Seconds to wait before the next submission.
+    pub wait: u64,
+}
+
+impl Verdict {
+    /// Parse the answer page returned by the website.
+    pub fn parse(page: &str) -> Result<Self> {
+        let text = match (page.find("<article>"), page.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &page[start..end],
+            _ => page,
+        };
+        let text = html::decode_entities(&html::strip_tags(text));
+        let outcome = if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::TooRecent
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            return Err(anyhow!("unexpected answer page: '{}'", text.trim()));
+        };
+        Ok(Self { outcome, wait: wait(&text) })
+    }
+}
+
+/// Seconds to wait from a message such as "You have 1m 5s left to wait" or "Please wait one minute".
+fn wait(text: &str) -> u64 {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("have ").map_or(0, |idx| idx + 5);
+        return text[start..end]
+            .split_whitespace()
+            .map(|token| match token.split_at(token.len().saturating_sub(1)) {
+                (value, "h") => value.parse().unwrap_or(0) * 3600,
+                (value, "m") => value.parse().unwrap_or(0) * 60,
+                (value, "s") => value.parse().unwrap_or(0),
+                _ => 0,
+            })
+            .sum();
+    }
+    if let Some(start) = text.find("wait ") {
+        let mut words = text[start + 5..].split_whitespace();
+        let count = match words.next() {
+            Some("one") => 1,
+            Some("five") => 5,
+            Some("ten") => 10,
+            Some(count) => count.parse().unwrap_or(0),
+            None => 0,
+        };
+        return match words.next() {
+            Some(unit) if unit.starts_with("minute") => count * 60,
+            Some(unit) if unit.starts_with("second") => count,
+            _ => 0,
+        };
+    }
+    0
+}
+
+/// Submission recorded in the log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl FromStr for Attempt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.splitn(7, '\t').collect();
+        let [timestamp, year, day, part, outcome, wait, answer] = fields[..] else {
+            return Err(anyhow!("invalid attempt: '{}'", s));
+        };
+        Ok(Attempt {
+            timestamp: timestamp.parse().with_context(|| format!("invalid timestamp: '{}'", timestamp))?,
+            year: year.parse().with_context(|| format!("invalid year: '{}'", year))?,
+            day: day.parse().with_context(|| format!("invalid day: '{}'", day))?,
+            part: part.parse()?,
+            answer: answer.to_owned(),
+            verdict: Verdict {
+                outcome: outcome.parse()?,
+                wait: wait.parse().with_context(|| format!("invalid wait: '{}'", wait))?,
+            },
+        })
+    }
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.verdict.outcome.key(),
+            self.verdict.wait,
+            self.answer
+        )
+    }
+}
+
+/// Submits answers, checking and recording them in the attempts log and the answers file.
+pub struct Submitter<'a> {
+    backend: &'a dyn Backend,
+    log: PathBuf,
+    answers: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl<'a> Submitter<'a> {
+    pub fn new(backend: &'a dyn Backend, log: &Path, answers: &Path) -> Result<Self> {
+        let attempts = if log.exists() {
+            let contents = std::fs::read_to_string(log).with_context(|| format!("cannot read attempts '{}'", log.display()))?;
+            contents.lines().filter(|line| !line.is_empty()).map(str::parse).collect::<Result<_>>()?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            backend,
+            log: log.to_owned(),
+            answers: answers.to_owned(),
+            attempts,
+        })
+    }
+
+    /// Every recorded attempt, oldest first.
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Submit an answer at `now`, in seconds since the epoch, recording a correct one in the answers file.
+    ///
+    /// Fails without posting when the answer is empty or has control characters, is known to be wrong,
+    /// or when the website asked to wait.
+    pub fn submit(&mut self, year: u32, day: u32, part: Part, answer: &str, now: u64) -> Result<Verdict> {
+        self.check(year, day, part, answer, now)?;
+        let level = match part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let form = format!("level={}&answer={}", level, http::form_encode(answer));
+        let response = self.backend.post(&format!("/{}/day/{}/answer", year, day), &form)?;
+        if !response.is_success() {
+            return Err(anyhow!("{}-{:02} part {}: submission failed with status {}", year, day, part, response.status));
+        }
+        let verdict = Verdict::parse(&response.body)?;
+        let attempt = Attempt {
+            timestamp: now,
+            year,
+            day,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.log)
+            .with_context(|| format!("cannot open attempts '{}'", self.log.display()))?;
+        writeln!(file, "{}", attempt)?;
+        self.attempts.push(attempt);
+        if verdict.outcome == Outcome::Correct {
+            let mut answers = if self.answers.exists() { Answers::load(&self.answers)? } else { Answers::default() };
+            answers.insert(year, day, part, answer);
+            answers.save(&self.answers)?;
+        }
+        Ok(verdict)
+    }
+
+    fn check(&self, year: u32, day: u32, part: Part, answer: &str, now: u64) -> Result<()> {
+        // Tabs and newlines would break the attempts log, and no answer has them.
+        if answer.is_empty() || answer.contains(char::is_control) {
+            return Err(anyhow!("invalid answer: '{}'", answer.escape_default()));
+        }
+        if let Some(last) = self.attempts.iter().rev().find(|attempt| attempt.verdict.wait > 0) {
+            let until = last.timestamp + last.verdict.wait;
+            if now < until {
+                return Err(anyhow!("rate limited, wait {}s before submitting again", until - now));
+            }
+        }
+        let value: Option<i64> = answer.parse().ok();
+        for attempt in self.attempts.iter().filter(|attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part)) {
+            let known = attempt.verdict.outcome;
+            if known == Outcome::Correct {
+                return Err(anyhow!("{}-{:02} part {} is already solved: '{}'", year, day, part, attempt.answer));
+            }
+            if attempt.answer == answer && known.is_checked() {
+                return Err(anyhow!("'{}' was already submitted: {}", answer, known));
+            }
+            if let (Some(value), Ok(bound)) = (value, attempt.answer.parse::<i64>()) {
+                if (known == Outcome::TooHigh && value >= bound) || (known == Outcome::TooLow && value <= bound) {
+                    return Err(anyhow!("'{}' cannot be right, '{}' was {}", answer, attempt.answer, known));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn verdict() -> Result<()> {
+    let page = |text: &str| format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", text);
+    let verdict = Verdict::parse(&page(
+        "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to saving your vacation.",
+    ))?;
+    assert_eq!(verdict, Verdict { outcome: Outcome::Correct, wait: 0 });
+    let verdict = Verdict::parse(&page(
+        "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; \
+         there are also some general tips on the <a href=\"/2015/about\">about page</a>.  Please wait one minute before trying again.",
+    ))?;
+    assert_eq!(verdict, Verdict { outcome: Outcome::TooHigh, wait: 60 });
+    let verdict = Verdict::parse(&page("That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again."))?;
+    assert_eq!(verdict, Verdict { outcome: Outcome::TooLow, wait: 300 });
+    let verdict = Verdict::parse(&page("That's not the right answer.  Please wait one minute before trying again."))?;
+    assert_eq!(
+        verdict,
+        Verdict {
+            outcome: Outcome::Incorrect,
+            wait: 60
+        }
+    );
+    let verdict = Verdict::parse(&page(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+    ))?;
+    assert_eq!(
+        verdict,
+        Verdict {
+            outcome: Outcome::TooRecent,
+            wait: 65
+        }
+    );
+    let verdict = Verdict::parse(&page("You don't seem to be solving the right level.  Did you already complete it?"))?;
+    assert_eq!(
+        verdict,
+        Verdict {
+            outcome: Outcome::WrongLevel,
+            wait: 0
+        }
+    );
+    assert!(Verdict::parse(&page("Something else")).is_err());
+    Ok(())
+}
+
+#[test]
+fn attempt() -> Result<()> {
+    let attempt = Attempt {
+        timestamp: 1640995200,
+        year: 2018,
+        day: 6,
+        part: Part::Two,
+        answer: "233,36".to_owned(),
+        verdict: Verdict { outcome: Outcome::TooLow, wait: 60 },
+    };
+    assert_eq!(attempt.to_string(), "1640995200\t2018\t6\ttwo\ttoo_low\t60\t233,36");
+    assert_eq!(attempt.to_string().parse::<Attempt>()?, attempt);
+    assert!("1640995200\t2018\t6".parse::<Attempt>().is_err());
+    Ok(())
+}
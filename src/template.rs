@@ -0,0 +1,97 @@
+//! Templates of the day crates, stored as files under `templates/` at the workspace root.
+//!
+//! - `manifest.toml` is the `Cargo.toml` of a day crate,
+//! - `test.rs` is its `tests/<crate>.rs`, checking the recorded answers,
+//! - `lib/<name>.rs` is its `src/lib.rs`, one per kind of puzzle,
+//! - `example.rs` is the test appended to `src/lib.rs` for each example of the puzzle.
+//!
+//! Placeholders are `{{year}}`, `{{day}}`, `{{dd}}` (the zero-padded day) and `{{crate}}`,
+//! plus `{{title}}`, `{{examples}}` in the library and `{{example}}`, the example number, in the example test.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+
+/// Directory of the templates, relative to the workspace root.
+pub const DIR: &str = "templates";
+
+/// Template used when none is chosen.
+pub const DEFAULT: &str = "default";
+
+/// Templates of the files of a day crate.
+#[derive(Clone, Debug)]
+pub struct Template {
+    pub name: String,
+    manifest: String,
+    test: String,
+    lib: String,
+    example: String,
+}
+
+impl Template {
+    /// Load a template of the workspace by name, such as `default` or `grid`.
+    pub fn load(root: &Path, name: &str) -> Result<Self> {
+        let dir = root.join(DIR);
+        let read = |file: &str| {
+            let path = dir.join(file);
+            std::fs::read_to_string(&path).with_context(|| format!("cannot read template '{}'", path.display()))
+        };
+        if !dir.join(format!("lib/{}.rs", name)).exists() {
+            return Err(anyhow!("unknown template: '{}', expected one of {}", name, names(root)?.join(", ")));
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            manifest: read("manifest.toml")?,
+            test: read("test.rs")?,
+            lib: read(&format!("lib/{}.rs", name))?,
+            example: read("example.rs")?,
+        })
+    }
+
+    /// `Cargo.toml` of a day crate.
+    pub fn manifest(&self, year: u32, day: u32) -> String {
+        render(&self.manifest, year, day)
+    }
+
+    /// `tests/<crate>.rs` of a day crate.
+    pub fn test(&self, year: u32, day: u32) -> String {
+        render(&self.test, year, day)
+    }
+
+    /// `src/lib.rs` of a day crate, `title` being the full title of the puzzle.
+    pub fn lib(&self, year: u32, day: u32, title: &str, examples: usize) -> String {
+        let title = title.split_once(": ").map_or(title, |(_, title)| title);
+        let examples: String = (1..=examples).map(|example| format!("\n{}", self.example.replace("{{example}}", &example.to_string()))).collect();
+        render(&self.lib, year, day)
+            .replace("{{title}}", &title.escape_debug().to_string())
+            .replace("{{examples}}\n", &examples)
+            .replace("{{examples}}", &examples)
+    }
+}
+
+/// Names of the templates of the workspace, in order.
+pub fn names(root: &Path) -> Result<Vec<String>> {
+    let dir = root.join(DIR).join("lib");
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(&dir).with_context(|| format!("cannot read templates '{}'", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            names.extend(path.file_stem().and_then(|stem| stem.to_str()).map(str::to_owned));
+        }
+    }
+    names.sort_unstable();
+    Ok(names)
+}
+
+/// Name of the crate of a day.
+pub fn crate_name(year: u32, day: u32) -> String {
+    format!("aoc_{}_day_{:02}", year, day)
+}
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{dd}}", &format!("{:02}", day))
+        .replace("{{crate}}", &crate_name(year, day))
+}
@@ -0,0 +1,111 @@
+//! Travelling salesman problems, small enough for the Held–Karp dynamic programming.
+//!
+//! Nodes are numbered from 0 and the cost of going from a node to another given by a matrix, which need not be symmetric.
+//! Time grows as `2^n * n^2`, which is fine up to a dozen or so nodes.
+
+use crate::search::Cost;
+
+/// Whether the best route is the cheapest or the most expensive one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Objective {
+    Minimise,
+    Maximise,
+}
+
+impl Objective {
+    fn better<C: Ord>(self, cost: C, than: C) -> bool {
+        match self {
+            Objective::Minimise => cost < than,
+            Objective::Maximise => cost > than,
+        }
+    }
+}
+
+/// Whether the route ends where it started.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tour {
+    Open,
+    Closed,
+}
+
+/// Nodes of a route in order, the return to the first one of a closed tour being implied, with its total cost.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Route<C> {
+    pub nodes: Vec<usize>,
+    pub cost: C,
+}
+
+/// Best route visiting every node once, from `start` or from any node when `None`.
+pub fn solve<C: Cost>(costs: &[Vec<C>], objective: Objective, tour: Tour, start: Option<usize>) -> Option<Route<C>> {
+    let nodes = costs.len();
+    if nodes == 0 || start.is_some_and(|start| start >= nodes) || costs.iter().any(|row| row.len() != nodes) {
+        return None;
+    }
+    // A closed tour passes through every node, so it may as well start from the first one.
+    let start = start.or((tour == Tour::Closed).then_some(0));
+
+    // Best cost of the routes visiting the nodes of a set and ending at a node, with the node before it.
+    let mut best: Vec<Vec<Option<(C, usize)>>> = vec![vec![None; nodes]; 1 << nodes];
+    for node in (0..nodes).filter(|&node| start.is_none_or(|start| start == node)) {
+        best[1 << node][node] = Some((C::default(), node));
+    }
+    for set in 1..1usize << nodes {
+        for last in 0..nodes {
+            let Some((cost, _)) = best[set][last] else { continue };
+            for next in (0..nodes).filter(|&next| set & 1 << next == 0) {
+                let cost = cost + costs[last][next];
+                let entry = &mut best[set | 1 << next][next];
+                if entry.is_none_or(|(current, _)| objective.better(cost, current)) {
+                    *entry = Some((cost, last));
+                }
+            }
+        }
+    }
+
+    let all = (1 << nodes) - 1;
+    let ends = (0..nodes).filter_map(|last| {
+        let (cost, _) = best[all][last]?;
+        match (tour, start) {
+            (Tour::Closed, Some(start)) => Some((cost + costs[last][start], last)),
+            _ => Some((cost, last)),
+        }
+    });
+    let (cost, mut last) = ends.reduce(|best, end| if objective.better(end.0, best.0) { end } else { best })?;
+
+    let mut route = Route {
+        nodes: Vec::with_capacity(nodes),
+        cost,
+    };
+    let mut set = all;
+    while set != 0 {
+        route.nodes.push(last);
+        let (_, previous) = best[set][last]?;
+        set &= !(1 << last);
+        last = previous;
+    }
+    route.nodes.reverse();
+    Some(route)
+}
+
+#[test]
+fn routes() {
+    // Four nodes on a line at 0, 1, 3 and 7, plus a shortcut from the last one back to the first.
+    let position: [i32; 4] = [0, 1, 3, 7];
+    let mut costs: Vec<Vec<i32>> = position.iter().map(|&a| position.iter().map(|&b| (a - b).abs()).collect()).collect();
+    costs[3][0] = 1;
+
+    let route = solve(&costs, Objective::Minimise, Tour::Open, None);
+    assert_eq!(route, Some(Route { nodes: vec![3, 0, 1, 2], cost: 4 }));
+    let route = solve(&costs, Objective::Minimise, Tour::Open, Some(2));
+    assert_eq!(route, Some(Route { nodes: vec![2, 3, 0, 1], cost: 6 }));
+    let route = solve(&costs, Objective::Minimise, Tour::Closed, None);
+    assert_eq!(route, Some(Route { nodes: vec![0, 1, 2, 3], cost: 8 }));
+    let route = solve(&costs, Objective::Maximise, Tour::Open, None);
+    assert_eq!(route.map(|route| route.cost), Some(16));
+    let route = solve(&costs, Objective::Maximise, Tour::Closed, Some(1));
+    assert_eq!(route.map(|route| route.cost), Some(18));
+
+    assert_eq!(solve(&[vec![0]], Objective::Minimise, Tour::Closed, None), Some(Route { nodes: vec![0], cost: 0 }));
+    assert_eq!(solve::<i32>(&[], Objective::Minimise, Tour::Open, None), None);
+    assert_eq!(solve(&costs, Objective::Minimise, Tour::Open, Some(4)), None);
+}
@@ -0,0 +1,7 @@
+#[test]
+#[ignore = "expected answer not filled in"]
+fn part_one_example{{example}}() -> Result<()> {
+    let input = Solver::parse(include_str!("../input/example_{{example}}.txt"))?;
+    assert_eq!(Solver::part_one(&input)?, 1);
+    Ok(())
+}
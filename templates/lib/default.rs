@@ -0,0 +1,27 @@
+use anyhow::Result;
+use aoc::Solution;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = {{year}};
+    const DAY: u32 = {{day}};
+    const TITLE: &'static str = "{{title}}";
+
+    type Input = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::PartOne> {
+        Ok(1)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(2)
+    }
+}
+{{examples}}
@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Error, Result};
+use aoc::Solution;
+
+/// Line of the input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Line(String);
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Line(s.to_owned()))
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const YEAR: u32 = {{year}};
+    const DAY: u32 = {{day}};
+    const TITLE: &'static str = "{{title}}";
+
+    type Input = Vec<Line>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| line.parse().with_context(|| format!("line {}: invalid line: '{}'", idx + 1, line)))
+            .collect()
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::PartOne> {
+        Ok(1)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(2)
+    }
+}
+{{examples}}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use aoc::{answers, Part};
+
+use {{crate}}::Solver;
+
+#[test]
+fn part_one_answer() -> Result<()> {
+    answers::check::<Solver>(Part::One)
+}
+
+#[test]
+fn part_two_answer() -> Result<()> {
+    answers::check::<Solver>(Part::Two)
+}
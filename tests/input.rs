@@ -0,0 +1,24 @@
+mod common;
+
+use anyhow::Result;
+use aoc::input;
+
+use common::TempDir;
+
+#[test]
+fn load() -> Result<()> {
+    let root = TempDir::new();
+    let path = input::path_in(root.path(), None, 2015, 1);
+    assert_eq!(path, root.path().join("aoc_2015/day_01/input/input.txt"));
+    assert_eq!(input::path_in(root.path(), Some(&root.path().join("inputs")), 2015, 1), root.path().join("inputs/2015/01.txt"));
+    assert_eq!(input::load_from(&path, None)?, None);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, "(()))(\n")?;
+    assert_eq!(input::load_from(&path, None)?.as_deref(), Some("(()))(\n"));
+    std::fs::write(input::encrypted_path(&path), input::encrypt("(()))(\n", "hunter2")?)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(input::load_from(&path, None)?, None);
+    assert_eq!(input::load_from(&path, Some("hunter2"))?.as_deref(), Some("(()))(\n"));
+    assert!(input::load_from(&path, Some("hunter3")).is_err());
+    Ok(())
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use anyhow::Result;
+use aoc::{
+    http::Client,
+    session::{Session, Source},
+};
+
+use common::{Server, TempDir};
+
+#[test]
+fn lookup_order() -> Result<()> {
+    let dir = TempDir::new();
+    let (workspace, config) = (dir.path().join("workspace"), dir.path().join("config"));
+    std::fs::create_dir_all(workspace.join("aoc_2015/day_01"))?;
+    std::fs::create_dir_all(config.join("aoc"))?;
+    let nested = workspace.join("aoc_2015/day_01");
+    assert!(Session::find_in(None, &nested, Some(&config)).is_err());
+    std::fs::write(config.join("aoc/session"), "c0ffee\n")?;
+    let session = Session::find_in(None, &nested, Some(&config))?;
+    assert_eq!(session.cookie, "c0ffee");
+    assert_eq!(session.source, Source::File(config.join("aoc/session")));
+    std::fs::write(workspace.join(".aoc-session"), "session=beef\n")?;
+    let session = Session::find_in(None, &nested, Some(&config))?;
+    assert_eq!(session.cookie, "beef");
+    assert_eq!(session.source, Source::File(workspace.join(".aoc-session")));
+    let session = Session::find_in(Some("cafe".to_owned()), &nested, Some(&config))?;
+    assert_eq!(
+        session,
+        Session {
+            cookie: "cafe".to_owned(),
+            source: Source::Env
+        }
+    );
+    std::fs::write(workspace.join(".aoc-session"), "")?;
+    let error = Session::find_in(None, &nested, Some(&config)).unwrap_err();
+    assert!(error.to_string().contains(".aoc-session"));
+    Ok(())
+}
+
+#[test]
+fn validate() -> Result<()> {
+    let server = Server::start(|request| match request.headers.get("cookie").map(String::as_str) {
+        Some("session=c0ffee") => (200, "<html>settings</html>".to_owned()),
+        _ => (302, String::new()),
+    });
+    let session = Session::find_in(Some("c0ffee".to_owned()), &std::env::temp_dir(), None)?;
+    session.validate(&Client::new(server.url(), &session.cookie)?)?;
+    let expired = Session::find_in(Some("beef".to_owned()), &std::env::temp_dir(), None)?;
+    let error = expired.validate(&Client::new(server.url(), &expired.cookie)?).unwrap_err();
+    assert!(error.to_string().contains("AOC_SESSION was rejected with status 302"));
+    assert_eq!(server.requests()[0].path, "/settings");
+    Ok(())
+}
@@ -0,0 +1,226 @@
+mod common;
+
+use std::path::Path;
+
+use anyhow::Result;
+use aoc::{
+    http::Client,
+    setup::{check_drift, refresh_readmes, setup_layout, Mode},
+    template::Template,
+};
+
+use common::{Server, TempDir};
+
+const PUZZLE: &str = include_str!("fixtures/2015_day_01.html");
+const INPUT: &str = "(()))(\n";
+
+/// Puzzle page and input of 2015 day 1.
+const DAY_ONE: &[(&str, &str)] = &[("/2015/day/1", PUZZLE), ("/2015/day/1/input", INPUT)];
+
+/// Server with the given pages, a client of it and an empty workspace.
+fn fixture(pages: &[(&str, &str)]) -> Result<(Server, Client, TempDir)> {
+    let server = Server::pages(pages);
+    let client = Client::new(server.url(), "secret")?;
+    Ok((server, client, workspace()?))
+}
+
+fn workspace() -> Result<TempDir> {
+    let root = TempDir::new();
+    std::fs::write(root.path().join("Cargo.toml"), "[workspace]\nmembers = [\"aoc_2015/day_*\", \"runner\"]\n")?;
+    std::fs::create_dir_all(root.path().join("runner/src"))?;
+    std::fs::write(root.path().join("runner/Cargo.toml"), "[dependencies]\naoc = { path = \"..\" }\n")?;
+    std::fs::write(root.path().join("runner/src/registry.rs"), "pub const SOLUTIONS: &[&dyn DynSolution] = &[\n];\n")?;
+    Ok(root)
+}
+
+fn template(name: &str) -> Result<Template> {
+    Template::load(Path::new(env!("CARGO_MANIFEST_DIR")), name)
+}
+
+fn read(root: &Path, path: &str) -> Result<String> {
+    Ok(std::fs::read_to_string(root.join(path))?)
+}
+
+#[test]
+fn setup_day() -> Result<()> {
+    let (server, client, root) = fixture(DAY_ONE)?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 1, Mode::Create)?;
+    let cargo = read(root.path(), "aoc_2015/day_01/Cargo.toml")?;
+    assert!(cargo.contains("name = \"aoc_2015_day_01\""));
+    let lib = read(root.path(), "aoc_2015/day_01/src/lib.rs")?;
+    assert!(lib.contains("const YEAR: u32 = 2015;"));
+    assert!(lib.contains("const DAY: u32 = 1;"));
+    assert!(lib.contains("const TITLE: &'static str = \"Not Quite Lisp\";"));
+    assert!(lib.contains("fn part_one_example1() -> Result<()> {"));
+    assert!(lib.contains("include_str!(\"../input/example_1.txt\")"));
+    assert!(!lib.contains("fn part_one_example2()"));
+    let test = read(root.path(), "aoc_2015/day_01/tests/aoc_2015_day_01.rs")?;
+    assert!(test.contains("use aoc_2015_day_01::Solver;"));
+    let readme = read(root.path(), "aoc_2015/day_01/README.md")?;
+    assert!(readme.contains(&format!("[Day 1: Not Quite Lisp]({}/2015/day/1)", server.url())));
+    assert!(readme.contains("## Day 1: Not Quite Lisp\n\nSanta was hoping for a white Christmas"));
+    assert!(readme.contains("- `(((` and `(()(()(` both result in floor `3`."));
+    assert_eq!(read(root.path(), "aoc_2015/day_01/input/input.txt")?, INPUT);
+    assert_eq!(read(root.path(), "aoc_2015/day_01/input/example_1.txt")?, "(())\n()()\n");
+    let manifest = read(root.path(), "runner/Cargo.toml")?;
+    assert!(manifest.contains("aoc_2015_day_01 = { path = \"../aoc_2015/day_01\" }"));
+    let registry = read(root.path(), "runner/src/registry.rs")?;
+    assert!(registry.contains("    &aoc_2015_day_01::Solver,\n];"));
+    Ok(())
+}
+
+#[test]
+fn session_cookie() -> Result<()> {
+    let (server, client, root) = fixture(DAY_ONE)?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 1, Mode::Create)?;
+    let requests = server.requests();
+    assert!(!requests.is_empty());
+    for request in requests {
+        assert_eq!(request.method, "GET");
+        assert!(request.body.is_empty());
+        assert_eq!(request.headers.get("cookie").map(String::as_str), Some("session=secret"));
+    }
+    Ok(())
+}
+
+#[test]
+fn existing_day_untouched() -> Result<()> {
+    let (server, client, root) = fixture(DAY_ONE)?;
+    std::fs::create_dir_all(root.path().join("aoc_2015/day_01"))?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 1, Mode::Create)?;
+    assert!(server.requests().is_empty());
+    assert!(!root.path().join("aoc_2015/day_01/Cargo.toml").exists());
+    Ok(())
+}
+
+#[test]
+fn unavailable_day_skipped() -> Result<()> {
+    let (server, client, root) = fixture(&[])?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 2, Mode::Create)?;
+    assert_eq!(server.requests().len(), 1);
+    assert!(!root.path().join("aoc_2015/day_02").exists());
+    Ok(())
+}
+
+#[test]
+fn refresh_readme() -> Result<()> {
+    let (_server, client, root) = fixture(&[("/2015/day/1", PUZZLE)])?;
+    std::fs::create_dir_all(root.path().join("aoc_2015/day_01/src"))?;
+    std::fs::write(root.path().join("aoc_2015/day_01/README.md"), "[](https://adventofcode.com/2015/day/1)\n")?;
+    std::fs::write(root.path().join("aoc_2015/day_01/src/lib.rs"), "// solution\n")?;
+    refresh_readmes(root.path(), &client, |_, _| true)?;
+    let readme = read(root.path(), "aoc_2015/day_01/README.md")?;
+    assert!(readme.contains("[Day 1: Not Quite Lisp]"));
+    assert!(!readme.contains("## Part Two"));
+    assert_eq!(read(root.path(), "aoc_2015/day_01/src/lib.rs")?, "// solution\n");
+    Ok(())
+}
+
+#[test]
+fn refresh_readme_part_two() -> Result<()> {
+    let page = PUZZLE.replace(
+        "</article>",
+        "</article><p>Your puzzle answer was <code>138</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Now, find the position.</p></article>",
+    );
+    let (_server, client, root) = fixture(&[("/2015/day/1", &page)])?;
+    std::fs::create_dir_all(root.path().join("aoc_2015/day_01"))?;
+    refresh_readmes(root.path(), &client, |_, _| true)?;
+    let readme = read(root.path(), "aoc_2015/day_01/README.md")?;
+    assert!(readme.contains("## Part Two\n\nNow, find the position.\n"));
+    assert!(!readme.contains("Your puzzle answer"));
+    Ok(())
+}
+
+#[test]
+fn update_fills_missing() -> Result<()> {
+    let (server, client, root) = fixture(DAY_ONE)?;
+    std::fs::create_dir_all(root.path().join("aoc_2015/day_01/src"))?;
+    std::fs::write(root.path().join("aoc_2015/day_01/src/lib.rs"), "// solution\n")?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 1, Mode::Update)?;
+    assert_eq!(read(root.path(), "aoc_2015/day_01/src/lib.rs")?, "// solution\n");
+    assert!(read(root.path(), "aoc_2015/day_01/Cargo.toml")?.contains("name = \"aoc_2015_day_01\""));
+    assert!(read(root.path(), "aoc_2015/day_01/tests/aoc_2015_day_01.rs")?.contains("use aoc_2015_day_01::Solver;"));
+    assert!(read(root.path(), "aoc_2015/day_01/README.md")?.contains("[Day 1: Not Quite Lisp]"));
+    assert_eq!(read(root.path(), "aoc_2015/day_01/input/input.txt")?, INPUT);
+    assert_eq!(read(root.path(), "aoc_2015/day_01/input/example_1.txt")?, "(())\n()()\n");
+    assert!(read(root.path(), "runner/src/registry.rs")?.contains("&aoc_2015_day_01::Solver,"));
+    let requests = server.requests().len();
+    std::fs::write(root.path().join("aoc_2015/day_01/README.md"), "# notes\n")?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 1, Mode::Update)?;
+    assert_eq!(server.requests().len(), requests);
+    assert_eq!(read(root.path(), "aoc_2015/day_01/README.md")?, "# notes\n");
+    Ok(())
+}
+
+#[test]
+fn new_year_member() -> Result<()> {
+    let page = PUZZLE.replace("/2015/", "/2022/");
+    let (_server, client, root) = fixture(&[("/2022/day/1", &page), ("/2022/day/1/input", INPUT)])?;
+    setup_layout(root.path(), &client, &template("default")?, 2022, 1, Mode::Create)?;
+    setup_layout(root.path(), &client, &template("default")?, 2015, 1, Mode::Create)?;
+    assert_eq!(read(root.path(), "Cargo.toml")?, "[workspace]\nmembers = [\"aoc_2022/day_*\", \"aoc_2015/day_*\", \"runner\"]\n");
+    Ok(())
+}
+
+#[test]
+fn refresh_selected_readmes() -> Result<()> {
+    let (server, client, root) = fixture(&[("/2015/day/1", PUZZLE)])?;
+    std::fs::create_dir_all(root.path().join("aoc_2015/day_01"))?;
+    std::fs::create_dir_all(root.path().join("aoc_2015/day_02"))?;
+    refresh_readmes(root.path(), &client, |_, day| day == 1)?;
+    assert_eq!(server.requests().len(), 1);
+    assert!(!root.path().join("aoc_2015/day_02/README.md").exists());
+    Ok(())
+}
+
+#[test]
+fn choose_template() -> Result<()> {
+    let (_server, client, root) = fixture(DAY_ONE)?;
+    setup_layout(root.path(), &client, &template("grid")?, 2015, 1, Mode::Create)?;
+    let lib = read(root.path(), "aoc_2015/day_01/src/lib.rs")?;
+    assert!(lib.contains("type Input = Grid<char>;"));
+    assert!(lib.contains("const TITLE: &'static str = \"Not Quite Lisp\";"));
+    assert!(lib.ends_with("}\n\n#[test]\n#[ignore = \"expected answer not filled in\"]\nfn part_one_example1() -> Result<()> {\n    let input = Solver::parse(include_str!(\"../input/example_1.txt\"))?;\n    assert_eq!(Solver::part_one(&input)?, 1);\n    Ok(())\n}\n"));
+    let error = template("sudoku").unwrap_err();
+    assert!(error.to_string().contains("expected one of default, grid, parsing"));
+    Ok(())
+}
+
+#[test]
+fn drift() -> Result<()> {
+    let (_server, client, root) = fixture(&[("/2015/day/1", PUZZLE), ("/2015/day/1/input", INPUT), ("/2015/day/2", PUZZLE), ("/2015/day/2/input", INPUT)])?;
+    let template = template("default")?;
+    setup_layout(root.path(), &client, &template, 2015, 1, Mode::Create)?;
+    setup_layout(root.path(), &client, &template, 2015, 2, Mode::Create)?;
+    let manifest = root.path().join("aoc_2015/day_01/Cargo.toml");
+    std::fs::write(&manifest, read(root.path(), "aoc_2015/day_01/Cargo.toml")? + "md-5 = \"0.10\"\n")?;
+    std::fs::write(root.path().join("aoc_2015/day_01/src/lib.rs"), "// solution\n")?;
+    assert!(check_drift(root.path(), &template)?.is_empty());
+    std::fs::write(&manifest, read(root.path(), "aoc_2015/day_01/Cargo.toml")?.replace("2021", "2018"))?;
+    std::fs::remove_file(root.path().join("aoc_2015/day_02/tests/aoc_2015_day_02.rs"))?;
+    let drifts: Vec<_> = check_drift(root.path(), &template)?.iter().map(ToString::to_string).collect();
+    assert_eq!(drifts, ["aoc_2015/day_01/Cargo.toml: differs from the template", "aoc_2015/day_02/tests/aoc_2015_day_02.rs: missing"]);
+    Ok(())
+}
+
+#[test]
+fn readmes_decoded() -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut encoded = Vec::new();
+    for year in std::fs::read_dir(root)? {
+        let year = year?;
+        if !year.file_name().to_string_lossy().starts_with("aoc_") {
+            continue;
+        }
+        for day in std::fs::read_dir(year.path())? {
+            let path = day?.path().join("README.md");
+            let Ok(readme) = std::fs::read_to_string(&path) else { continue };
+            if aoc::html::decode_entities(&readme) != readme {
+                encoded.push(path.strip_prefix(root)?.display().to_string());
+            }
+        }
+    }
+    encoded.sort_unstable();
+    assert!(encoded.is_empty(), "character references left in {:?}", encoded);
+    Ok(())
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Result;
+use aoc::{
+    answers::Answers,
+    http::Client,
+    submit::{Outcome, Submitter, Verdict},
+    Part,
+};
+
+use common::{Server, TempDir};
+
+fn page(text: &str) -> String {
+    format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n", text)
+}
+
+#[test]
+fn submit_correct() -> Result<()> {
+    let server = Server::start(|_| (200, page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")));
+    let client = Client::new(server.url(), "secret")?;
+    let dir = TempDir::new();
+    let (log, answers) = (dir.path().join("submissions.tsv"), dir.path().join("answers.toml"));
+    let mut submitter = Submitter::new(&client, &log, &answers)?;
+    let verdict = submitter.submit(2015, 1, Part::Two, "1771", 1000)?;
+    assert_eq!(verdict, Verdict { outcome: Outcome::Correct, wait: 0 });
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2015/day/1/answer");
+    assert_eq!(requests[0].body, "level=2&answer=1771");
+    assert_eq!(requests[0].headers.get("cookie").map(String::as_str), Some("session=secret"));
+    assert_eq!(Answers::load(&answers)?.get(2015, 1, Part::Two), Some("1771"));
+    assert!(submitter.submit(2015, 1, Part::Two, "1772", 2000).is_err());
+    assert_eq!(Submitter::new(&client, &log, &answers)?.attempts(), submitter.attempts());
+    Ok(())
+}
+
+#[test]
+fn wrong_answers_not_resubmitted() -> Result<()> {
+    let count = AtomicUsize::new(0);
+    let server = Server::start(move |_| match count.fetch_add(1, Ordering::SeqCst) {
+        0 => (200, page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.")),
+        _ => (200, page("That's not the right answer; your answer is too low.  Please wait one minute before trying again.")),
+    });
+    let client = Client::new(server.url(), "secret")?;
+    let dir = TempDir::new();
+    let (log, answers) = (dir.path().join("submissions.tsv"), dir.path().join("answers.toml"));
+    let mut submitter = Submitter::new(&client, &log, &answers)?;
+    assert_eq!(submitter.submit(2015, 1, Part::One, "200", 1000)?.outcome, Outcome::TooHigh);
+    assert!(submitter.submit(2015, 1, Part::One, "100", 1030).is_err());
+    assert_eq!(submitter.submit(2015, 1, Part::One, "100", 1060)?.outcome, Outcome::TooLow);
+    let mut submitter = Submitter::new(&client, &log, &answers)?;
+    assert!(submitter.submit(2015, 1, Part::One, "100", 2000).is_err());
+    assert!(submitter.submit(2015, 1, Part::One, "250", 2000).is_err());
+    assert!(submitter.submit(2015, 1, Part::One, "50", 2000).is_err());
+    assert_eq!(server.requests().len(), 2);
+    assert!(!answers.exists());
+    Ok(())
+}
+
+#[test]
+fn too_recent() -> Result<()> {
+    let server = Server::start(|_| {
+        (
+            200,
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait."),
+        )
+    });
+    let client = Client::new(server.url(), "secret")?;
+    let dir = TempDir::new();
+    let mut submitter = Submitter::new(&client, &dir.path().join("submissions.tsv"), &dir.path().join("answers.toml"))?;
+    assert_eq!(
+        submitter.submit(2015, 1, Part::One, "138", 1000)?,
+        Verdict {
+            outcome: Outcome::TooRecent,
+            wait: 34
+        }
+    );
+    assert!(submitter.submit(2015, 1, Part::One, "138", 1010).is_err());
+    assert_eq!(server.requests().len(), 1);
+    submitter.submit(2015, 1, Part::One, "138", 1034)?;
+    assert_eq!(server.requests().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn invalid_answers_rejected() -> Result<()> {
+    let server = Server::start(|_| (200, page("That's not the right answer.")));
+    let client = Client::new(server.url(), "secret")?;
+    let dir = TempDir::new();
+    let log = dir.path().join("submissions.tsv");
+    let mut submitter = Submitter::new(&client, &log, &dir.path().join("answers.toml"))?;
+    for answer in ["", "12\t34", "AB\nCD", "1771\n"] {
+        assert!(submitter.submit(2015, 1, Part::One, answer, 1000).is_err());
+    }
+    assert!(server.requests().is_empty());
+    assert!(!log.exists());
+    Ok(())
+}